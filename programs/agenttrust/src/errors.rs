@@ -8,7 +8,7 @@ pub enum AgentTrustError {
     #[msg("Task title exceeds maximum length")]
     TitleTooLong,
     
-    #[msg("Insufficient stake. Minimum 0.02 SOL required")]
+    #[msg("Insufficient stake for the configured minimum")]
     InsufficientStake,
     
     #[msg("Invalid bounty amount")]
@@ -76,4 +76,145 @@ pub enum AgentTrustError {
     
     #[msg("Fee calculation error")]
     FeeCalculationError,
+    
+    #[msg("Token account mint does not match the task mint")]
+    InvalidMint,
+    
+    #[msg("Token accounts are required for SPL token tasks")]
+    MissingTokenAccounts,
+    
+    #[msg("Invalid protocol config parameter")]
+    InvalidConfig,
+    
+    #[msg("Agent has claimed, submitted or disputed tasks outstanding")]
+    ActiveTasksOutstanding,
+    
+    #[msg("No stake is pending withdrawal")]
+    NoPendingUnstake,
+    
+    #[msg("Unbonding period has not elapsed")]
+    UnbondingNotComplete,
+    
+    #[msg("Task deadline has not passed")]
+    DeadlineNotPassed,
+    
+    #[msg("Client review window is still open")]
+    ReviewWindowActive,
+    
+    #[msg("Arbitrator is not active")]
+    ArbitratorNotActive,
+    
+    #[msg("Arbitrator registry is full")]
+    ArbitratorRegistryFull,
+    
+    #[msg("Not enough eligible arbitrators to seat a panel")]
+    NotEnoughArbitrators,
+    
+    #[msg("Arbitrator is not on this dispute's panel")]
+    NotPanelArbitrator,
+    
+    #[msg("Arbitrator has already committed a vote this round")]
+    AlreadyVoted,
+    
+    #[msg("Dispute has not been resolved")]
+    DisputeNotResolved,
+    
+    #[msg("Task is not under dispute")]
+    TaskNotDisputed,
+    
+    #[msg("Dispute is not open for committing votes")]
+    CommitPhaseClosed,
+    
+    #[msg("Dispute is not open for revealing votes")]
+    RevealPhaseClosed,
+    
+    #[msg("Revealed vote does not match the commitment")]
+    InvalidReveal,
+    
+    #[msg("Reveal deadline has not passed")]
+    RevealPeriodActive,
+    
+    #[msg("Arbitrator account for a forfeited seat was not supplied")]
+    MissingArbitratorAccount,
+    
+    #[msg("Split must give the agent between 0 and 100% exclusive")]
+    InvalidSplit,
+    
+    #[msg("Appeal window has closed")]
+    AppealWindowClosed,
+    
+    #[msg("Appeal window is still open")]
+    AppealWindowActive,
+    
+    #[msg("Dispute has already been appealed")]
+    AlreadyAppealed,
+    
+    #[msg("Only the losing party can appeal")]
+    NotLosingParty,
+    
+    #[msg("Default judgment needs evidence from exactly one party")]
+    NoDefaultJudgment,
+    
+    #[msg("Task cannot be disputed in its current status")]
+    TaskNotDisputable,
+    
+    #[msg("Arbitrator stake is below the configured minimum")]
+    ArbitratorStakeTooLow,
+    
+    #[msg("Arbitrator stake is locked until unbonding after suspension")]
+    ArbitratorStakeLocked,
+    
+    #[msg("Account does not belong to this task")]
+    TaskAccountMismatch,
+    
+    #[msg("Agent reputation is below the task's minimum")]
+    ReputationTooLow,
+    
+    #[msg("Agent stake is below the task's minimum")]
+    AgentStakeTooLow,
+    
+    #[msg("Agent has fewer successful tasks than the task requires")]
+    NotEnoughSuccessfulTasks,
+    
+    #[msg("Agent has lost more disputes than the task allows")]
+    TooManyDisputeLosses,
+    
+    #[msg("Agent's unbonded stake can't cover the task's collateral")]
+    InsufficientCollateral,
+    
+    #[msg("Collateral can only be a share of lamport bounties")]
+    InvalidCollateral,
+    
+    #[msg("The dispute's panel has already been seated")]
+    PanelAlreadySeated,
+    
+    #[msg("The dispute's panel has not been seated yet")]
+    PanelNotSeated,
+    
+    #[msg("No slot after the seating slot has been hashed yet")]
+    PanelSeatingNotReady,
+    
+    #[msg("Commit deadline has not passed")]
+    CommitPeriodActive,
+    
+    #[msg("Arbitrator must be suspended before it can be removed")]
+    ArbitratorStillActive,
+    
+    #[msg("Seats that missed the commit deadline must be forfeited with tally_votes first")]
+    CommitPhaseNotClosed,
+    
+    #[msg("Votes can't be revealed while seats can still commit")]
+    CommitPhaseOpen,
+    
+    #[msg("A bounty share of collateral can't exceed the bounty")]
+    CollateralExceedsBounty,
+    
+    #[msg("Arbitrator is not suspended")]
+    ArbitratorNotSuspended,
+    
+    #[msg("A panel can still be seated for this dispute")]
+    SeatingWindowOpen,
+    
+    #[msg("The deadline to seat a panel for this dispute has passed")]
+    SeatingWindowClosed,
 }
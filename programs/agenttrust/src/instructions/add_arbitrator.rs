@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::state::{Arbitrator, ArbitratorRegistry, ProtocolConfig, StakeVault};
+use crate::errors::AgentTrustError;
+
+#[derive(Accounts)]
+#[instruction(authority: Pubkey)]
+pub struct AddArbitrator<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ AgentTrustError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = Arbitrator::SPACE,
+        seeds = [b"arbitrator", authority.as_ref()],
+        bump
+    )]
+    pub arbitrator: Account<'info, Arbitrator>,
+    
+    /// Vault holding the arbitrator's staked lamports
+    #[account(
+        init,
+        payer = admin,
+        space = StakeVault::SPACE,
+        seeds = [b"stake_vault", arbitrator.key().as_ref()],
+        bump
+    )]
+    pub stake_vault: Account<'info, StakeVault>,
+    
+    /// Active set that dispute panels are drawn from
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ArbitratorRegistry::SPACE,
+        seeds = [b"arbitrator_registry"],
+        bump
+    )]
+    pub arbitrator_registry: Account<'info, ArbitratorRegistry>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<AddArbitrator>,
+    authority: Pubkey,
+) -> Result<()> {
+    let arbitrator = &mut ctx.accounts.arbitrator;
+    
+    arbitrator.register(authority, ctx.bumps.arbitrator)?;
+    
+    let stake_vault = &mut ctx.accounts.stake_vault;
+    stake_vault.owner = arbitrator.key();
+    stake_vault.bump = ctx.bumps.stake_vault;
+    
+    // Panels only draw arbitrators once they stake the configured minimum
+    let registry = &mut ctx.accounts.arbitrator_registry;
+    registry.bump = ctx.bumps.arbitrator_registry;
+    registry.sync(arbitrator, &ctx.accounts.config)?;
+    
+    msg!("Arbitrator added: {}", authority);
+    msg!("Minimum stake to join panels: {} lamports", ctx.accounts.config.min_arbitrator_stake_lamports);
+    msg!("Active arbitrators: {}", registry.arbitrators.len());
+    
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{Task, Dispute, ProtocolConfig, ArbitratorRegistry};
+use crate::errors::AgentTrustError;
+
+#[derive(Accounts)]
+pub struct AppealDispute<'info> {
+    /// Losing party posting the appeal bond
+    #[account(mut)]
+    pub appellant: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    pub task: Account<'info, Task>,
+    
+    /// Holds the appeal bond until the dispute is settled
+    #[account(
+        mut,
+        seeds = [b"dispute", task.key().as_ref()],
+        bump = dispute.bump
+    )]
+    pub dispute: Account<'info, Dispute>,
+    
+    /// Active arbitrators, which must be enough to seat an appeal panel
+    #[account(
+        seeds = [b"arbitrator_registry"],
+        bump = arbitrator_registry.bump
+    )]
+    pub arbitrator_registry: Account<'info, ArbitratorRegistry>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AppealDispute>) -> Result<()> {
+    let task = &ctx.accounts.task;
+    let dispute = &mut ctx.accounts.dispute;
+    let config = &ctx.accounts.config;
+    let appellant = ctx.accounts.appellant.key();
+    
+    let is_client = task.client == appellant;
+    let is_agent = task.agent == Some(appellant);
+    
+    require!(
+        is_client || is_agent,
+        AgentTrustError::Unauthorized
+    );
+    
+    // A fresh panel is seated by seat_panel from a later slot's hash
+    let bond = config.appeal_bond_lamports;
+    dispute.appeal(appellant, is_client, bond, config)?;
+    ctx.accounts.arbitrator_registry.check_can_seat(
+        dispute.panel_size(config),
+        &dispute.panel_exclusions(task.client, task.agent),
+    )?;
+    
+    // Bond is held by the dispute account until settlement
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.appellant.to_account_info(),
+                to: dispute.to_account_info(),
+            },
+        ),
+        bond,
+    )?;
+    
+    msg!("Dispute appealed by: {}", appellant);
+    msg!("Appeal bond: {} lamports", bond);
+    msg!("Appeal panel drawn after slot: {}", dispute.seating_slot);
+    msg!("Appeal panel must be seated by: {}", dispute.seating_deadline);
+    
+    Ok(())
+}
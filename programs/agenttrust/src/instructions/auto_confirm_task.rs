@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::state::ProtocolConfig;
+use crate::errors::AgentTrustError;
+use crate::instructions::confirm_task::*;
+
+#[derive(Accounts)]
+pub struct AutoConfirmTask<'info> {
+    /// Anyone can confirm a task once the client's review window lapses
+    pub cranker: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    pub payout: TaskPayout<'info>,
+}
+
+pub fn handler(ctx: Context<AutoConfirmTask>) -> Result<()> {
+    let review_window = ctx.accounts.config.review_window_seconds;
+    
+    require!(
+        ctx.accounts.payout.task.is_review_window_over(review_window),
+        AgentTrustError::ReviewWindowActive
+    );
+    
+    ctx.accounts.payout.release()?;
+    
+    msg!("Task auto-confirmed after {} second review window", review_window);
+    
+    Ok(())
+}
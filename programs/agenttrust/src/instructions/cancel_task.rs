@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{Task, Escrow, EscrowVault};
+use crate::errors::AgentTrustError;
+
+#[derive(Accounts)]
+pub struct CancelTask<'info> {
+    #[account(mut)]
+    pub client: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = task.client == client.key() @ AgentTrustError::NotClient,
+        constraint = task.status == crate::TaskStatus::Open @ AgentTrustError::TaskNotOpen
+    )]
+    pub task: Account<'info, Task>,
+    
+    /// Escrow is closed and its rent returned to the client
+    #[account(
+        mut,
+        seeds = [b"escrow", task.key().as_ref()],
+        bump = escrow.bump,
+        close = client
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    /// Token accounts below are only required for SPL token tasks
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow_vault", task.key().as_ref()],
+        bump
+    )]
+    pub escrow_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        constraint = client_token_account.owner == client.key() @ AgentTrustError::NotClient
+    )]
+    pub client_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler(ctx: Context<CancelTask>) -> Result<()> {
+    let vault = EscrowVault::from_accounts(
+        &ctx.accounts.escrow_vault,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+    );
+    let task = &mut ctx.accounts.task;
+    let escrow = &mut ctx.accounts.escrow;
+    let client = ctx.accounts.client.to_account_info();
+    
+    task.cancel()?;
+    
+    // Refund bounty + fee
+    let refund = escrow.balance;
+    Escrow::pay(
+        escrow,
+        vault.as_ref(),
+        &client,
+        ctx.accounts.client_token_account.as_ref(),
+        refund,
+    )?;
+    
+    // Reclaim the token vault's rent as well
+    if let Some(vault) = vault.as_ref() {
+        Escrow::close_vault(escrow, vault, &client)?;
+    }
+    
+    msg!("Task cancelled: {}", task.key());
+    msg!("Refund returned to client: {}", refund);
+    
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::{Task, Agent};
+use crate::state::{Task, Agent, Escrow, StakeVault};
 use crate::errors::AgentTrustError;
 
 #[derive(Accounts)]
@@ -14,18 +14,45 @@ pub struct ClaimTask<'info> {
     pub task: Account<'info, Task>,
     
     #[account(
+        mut,
         constraint = agent_account.owner == agent.key() @ AgentTrustError::Unauthorized
     )]
     pub agent_account: Account<'info, Agent>,
+    
+    /// Vault the task's collateral is taken from
+    #[account(
+        mut,
+        seeds = [b"stake_vault", agent_account.key().as_ref()],
+        bump = stake_vault.bump
+    )]
+    pub stake_vault: Account<'info, StakeVault>,
+    
+    /// Escrow holding the collateral until the task settles
+    #[account(
+        mut,
+        seeds = [b"escrow", task.key().as_ref()],
+        bump = escrow.bump
+    )]
+    pub escrow: Account<'info, Escrow>,
 }
 
 pub fn handler(ctx: Context<ClaimTask>) -> Result<()> {
     let task = &mut ctx.accounts.task;
-    let agent_key = ctx.accounts.agent.key();
+    let agent_account = &mut ctx.accounts.agent_account;
+    
+    // Rejects agents that fall short of the client's requirements
+    task.claim(agent_account)?;
+    agent_account.begin_task();
     
-    task.claim(agent_key)?;
+    // Lock the required collateral out of the agent's stake
+    let collateral = task.collateral;
+    if collateral > 0 {
+        agent_account.lock_collateral(collateral)?;
+        Escrow::lock_collateral(&mut ctx.accounts.escrow, &ctx.accounts.stake_vault, collateral)?;
+    }
     
-    msg!("Task claimed by agent: {}", agent_key);
+    msg!("Task claimed by agent: {}", agent_account.owner);
+    msg!("Collateral locked: {} lamports", collateral);
     
     Ok(())
 }
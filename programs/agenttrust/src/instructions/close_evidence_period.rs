@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::state::{Dispute, DisputeQueue};
+
+#[derive(Accounts)]
+pub struct CloseEvidencePeriod<'info> {
+    /// Anyone can close an evidence period once it has ended
+    pub cranker: Signer<'info>,
+    
+    #[account(mut)]
+    pub dispute: Account<'info, Dispute>,
+    
+    #[account(
+        mut,
+        seeds = [b"dispute_queue"],
+        bump = dispute_queue.bump
+    )]
+    pub dispute_queue: Account<'info, DisputeQueue>,
+}
+
+pub fn handler(ctx: Context<CloseEvidencePeriod>) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let queue = &mut ctx.accounts.dispute_queue;
+    
+    dispute.move_to_judgment()?;
+    queue.push(dispute.key());
+    
+    msg!("Evidence period closed for dispute: {}", dispute.key());
+    msg!("Disputes in the judgment queue: {}", queue.disputes.len());
+    
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::state::{Arbitrator, Dispute, ProtocolConfig};
+use crate::errors::AgentTrustError;
+
+#[derive(Accounts)]
+pub struct CommitVote<'info> {
+    /// Panel arbitrator sealing a vote
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    #[account(
+        seeds = [b"arbitrator", authority.key().as_ref()],
+        bump = arbitrator.bump,
+        constraint = arbitrator.is_active() @ AgentTrustError::ArbitratorNotActive,
+        constraint = arbitrator.total_stake >= config.min_arbitrator_stake_lamports
+            @ AgentTrustError::ArbitratorStakeTooLow
+    )]
+    pub arbitrator: Account<'info, Arbitrator>,
+    
+    #[account(mut)]
+    pub dispute: Account<'info, Dispute>,
+}
+
+pub fn handler(
+    ctx: Context<CommitVote>,
+    commitment: [u8; 32],
+) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    
+    dispute.commit_vote(ctx.accounts.authority.key(), commitment, &ctx.accounts.config)?;
+    
+    msg!("Vote committed by arbitrator: {}", ctx.accounts.authority.key());
+    msg!("Commit phase ends at: {}", dispute.commit_deadline);
+    msg!("Reveal phase ends at: {}", dispute.reveal_deadline);
+    
+    Ok(())
+}
@@ -1,57 +1,153 @@
 use anchor_lang::prelude::*;
-use crate::state::{Task, Agent};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{Task, Agent, Escrow, EscrowVault, Treasury, FeeLedger, StakeVault};
 use crate::errors::AgentTrustError;
-use crate::TRANSACTION_FEE_BPS;
 
 #[derive(Accounts)]
 pub struct ConfirmTask<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = payout.task.client == client.key() @ AgentTrustError::NotClient
+    )]
     pub client: Signer<'info>,
     
+    pub payout: TaskPayout<'info>,
+}
+
+/// Accounts that release a submitted task's escrow to its agent.
+/// Shared by `confirm_task` and `auto_confirm_task`.
+#[derive(Accounts)]
+pub struct TaskPayout<'info> {
     #[account(
         mut,
-        constraint = task.client == client.key() @ AgentTrustError::NotClient,
         constraint = task.status == crate::TaskStatus::Submitted @ AgentTrustError::TaskNotSubmitted
     )]
     pub task: Account<'info, Task>,
     
-    #[account(mut)]
+    /// Agent PDA of the wallet assigned to the task
+    #[account(
+        mut,
+        seeds = [b"agent", task.agent.unwrap_or_default().as_ref()],
+        bump = agent_account.bump
+    )]
     pub agent_account: Account<'info, Agent>,
     
-    /// CHECK: Escrow account
+    /// Vault the agent's collateral is returned to
+    #[account(
+        mut,
+        seeds = [b"stake_vault", agent_account.key().as_ref()],
+        bump = stake_vault.bump
+    )]
+    pub stake_vault: Account<'info, StakeVault>,
+    
+    /// Agent wallet receiving the bounty
+    #[account(
+        mut,
+        constraint = task.agent == Some(agent.key()) @ AgentTrustError::NotAssignedAgent
+    )]
+    pub agent: SystemAccount<'info>,
+    
+    /// Escrow holding the task's bounty and fee
     #[account(
         mut,
         seeds = [b"escrow", task.key().as_ref()],
-        bump = escrow.bump
+        bump = escrow.bump,
+        has_one = task @ AgentTrustError::TaskAccountMismatch
     )]
-    pub escrow: Account<'info, crate::instructions::create_task::Escrow>,
+    pub escrow: Account<'info, Escrow>,
     
-    pub system_program: Program<'info, System>,
-}
-
-pub fn handler(ctx: Context<ConfirmTask>) -> Result<()> {
-    let task = &mut ctx.accounts.task;
-    let agent_account = &mut ctx.accounts.agent_account;
-    let bounty = task.bounty;
+    /// Protocol treasury collecting the fee
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
     
-    // Confirm task
-    task.confirm()?;
+    /// Token accounts below are only required for SPL token tasks
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
     
-    // Calculate fee
-    let fee = (bounty * TRANSACTION_FEE_BPS) / 10_000;
-    let agent_payment = bounty - fee;
+    #[account(
+        mut,
+        seeds = [b"escrow_vault", task.key().as_ref()],
+        bump
+    )]
+    pub escrow_vault: Option<InterfaceAccount<'info, TokenAccount>>,
     
-    // Update agent stats
-    agent_account.record_success();
-    agent_account.update_reputation_score();
+    #[account(
+        mut,
+        constraint = agent_token_account.owner == agent.key() @ AgentTrustError::NotAssignedAgent
+    )]
+    pub agent_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
     
-    // Release funds from escrow to agent
-    // Note: In production, use system_program::transfer from escrow
+    #[account(mut)]
+    pub fee_ledger: Option<Account<'info, FeeLedger>>,
+    
+    #[account(mut)]
+    pub fee_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> TaskPayout<'info> {
+    /// Mark the task confirmed, credit the agent and release the escrow
+    pub fn release(&mut self) -> Result<()> {
+        let task = &mut self.task;
+        let agent_account = &mut self.agent_account;
+        let agent_payment = task.bounty;
+        let fee = task.fee;
+        
+        // Confirm task
+        task.confirm()?;
+        
+        // Update agent stats
+        agent_account.record_success();
+        agent_account.finish_task();
+        agent_account.update_reputation_score();
+        
+        // Release funds from escrow to agent and treasury
+        let vault = EscrowVault::from_accounts(
+            &self.escrow_vault,
+            &self.mint,
+            &self.token_program,
+        );
+        let escrow = &mut self.escrow;
+        Escrow::pay(
+            escrow,
+            vault.as_ref(),
+            &self.agent.to_account_info(),
+            self.agent_token_account.as_ref(),
+            agent_payment,
+        )?;
+        Treasury::collect_fee(
+            &mut self.treasury,
+            escrow,
+            vault.as_ref(),
+            self.fee_vault.as_mut(),
+            self.fee_ledger.as_mut(),
+            fee,
+        )?;
+        
+        // Return the agent's collateral to its stake
+        let collateral = escrow.collateral;
+        agent_account.unlock_collateral(collateral)?;
+        Escrow::release_collateral(escrow, &self.stake_vault.to_account_info(), collateral)?;
+        
+        msg!("Agent payment: {}", agent_payment);
+        msg!("Protocol fee: {}", fee);
+        msg!("Collateral released: {} lamports", collateral);
+        msg!("Agent new reputation: {}", agent_account.reputation_score);
+        
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<ConfirmTask>) -> Result<()> {
+    ctx.accounts.payout.release()?;
     
     msg!("Task confirmed and completed");
-    msg!("Agent payment: {} lamports", agent_payment);
-    msg!("Protocol fee: {} lamports", fee);
-    msg!("Agent new reputation: {}", agent_account.reputation_score);
     
     Ok(())
 }
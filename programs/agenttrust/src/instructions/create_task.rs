@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
-use crate::state::{Task, get_task_seeds};
+use anchor_lang::system_program;
+use crate::state::{Task, ClaimRequirements, Escrow, ClientProfile, ProtocolConfig};
 use crate::errors::AgentTrustError;
-use crate::TRANSACTION_FEE_BPS;
+use crate::Collateral;
 
 #[derive(Accounts)]
 #[instruction(title: String, description_hash: [u8; 32], bounty: u64)]
@@ -9,12 +10,28 @@ pub struct CreateTask<'info> {
     #[account(mut)]
     pub client: Signer<'info>,
     
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    /// Per-client task counter, created on the client's first task
+    #[account(
+        init_if_needed,
+        payer = client,
+        space = ClientProfile::SPACE,
+        seeds = [b"client", client.key().as_ref()],
+        bump
+    )]
+    pub client_profile: Account<'info, ClientProfile>,
+    
     /// CHECK: Task PDA will be initialized
     #[account(
         init,
         payer = client,
         space = Task::SPACE,
-        seeds = [b"task", client.key().as_ref(), &client_task_count.to_le_bytes()],
+        seeds = [b"task", client.key().as_ref(), &client_profile.task_count.to_le_bytes()],
         bump
     )]
     pub task: Account<'info, Task>,
@@ -23,7 +40,7 @@ pub struct CreateTask<'info> {
     #[account(
         init,
         payer = client,
-        space = 8 + 8, // discriminator + balance
+        space = Escrow::SPACE,
         seeds = [b"escrow", task.key().as_ref()],
         bump
     )]
@@ -32,30 +49,30 @@ pub struct CreateTask<'info> {
     pub system_program: Program<'info, System>,
 }
 
-#[account]
-pub struct Escrow {
-    pub balance: u64,
-    pub bump: u8,
-}
-
-impl Escrow {
-    pub const SPACE: usize = 8 + 8 + 1;
-}
-
 pub fn handler(
     ctx: Context<CreateTask>,
     title: String,
     description_hash: [u8; 32],
     bounty: u64,
     deadline: i64,
+    requirements: ClaimRequirements,
+    collateral: Collateral,
 ) -> Result<()> {
     let task = &mut ctx.accounts.task;
     let client = ctx.accounts.client.key();
     let bump = ctx.bumps.task;
     
-    // Calculate fee (1%)
-    let fee = (bounty * TRANSACTION_FEE_BPS) / 10_000;
-    let total_required = bounty + fee;
+    // Take the next task id from the client's counter
+    let client_profile = &mut ctx.accounts.client_profile;
+    client_profile.init_if_new(client, ctx.bumps.client_profile)?;
+    let task_id = client_profile.next_task_id()?;
+    
+    // Calculate protocol fee, surcharged for each dispute the client has lost
+    let disputes_lost = client_profile.disputes_lost;
+    let fee = ctx.accounts.config.calculate_fee(bounty, disputes_lost)?;
+    let total_required = bounty
+        .checked_add(fee)
+        .ok_or(AgentTrustError::Overflow)?;
     
     // Verify client has enough funds
     require!(
@@ -66,26 +83,43 @@ pub fn handler(
     // Initialize task
     task.create(
         client,
+        task_id,
         title.clone(),
         description_hash,
         bounty,
+        fee,
         deadline,
+        requirements,
+        collateral,
+        &ctx.accounts.config,
         bump,
     )?;
     
+    // Transfer bounty + fee from client to escrow
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.client.to_account_info(),
+                to: ctx.accounts.escrow.to_account_info(),
+            },
+        ),
+        total_required,
+    )?;
+    
     // Initialize escrow
     let escrow = &mut ctx.accounts.escrow;
-    escrow.balance = bounty;
+    escrow.task = task.key();
+    escrow.mint = None;
+    escrow.balance = total_required;
+    escrow.collateral = 0;
     escrow.bump = ctx.bumps.escrow;
     
-    // Transfer funds to escrow
-    // Note: In a complete implementation, we'd use system_program::transfer
-    // For now, we're tracking the intent
-    
-    msg!("Task created: {}", title);
+    msg!("Task created: {} (#{})", title, task_id);
     msg!("Bounty: {} lamports", bounty);
-    msg!("Fee: {} lamports (1%)", fee);
+    msg!("Fee: {} lamports ({} bps)", fee, ctx.accounts.config.client_fee_bps(disputes_lost));
     msg!("Deadline: {}", deadline);
+    msg!("Agent collateral: {} lamports", task.collateral);
     
     Ok(())
 }
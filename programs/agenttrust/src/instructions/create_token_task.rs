@@ -0,0 +1,205 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::{Task, ClaimRequirements, Escrow, ClientProfile, ProtocolConfig, Treasury, FeeLedger};
+use crate::errors::AgentTrustError;
+use crate::Collateral;
+
+#[derive(Accounts)]
+#[instruction(title: String, description_hash: [u8; 32], bounty: u64)]
+pub struct CreateTokenTask<'info> {
+    #[account(mut)]
+    pub client: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    /// Per-client task counter, created on the client's first task
+    #[account(
+        init_if_needed,
+        payer = client,
+        space = ClientProfile::SPACE,
+        seeds = [b"client", client.key().as_ref()],
+        bump
+    )]
+    pub client_profile: Account<'info, ClientProfile>,
+    
+    /// CHECK: Task PDA will be initialized
+    #[account(
+        init,
+        payer = client,
+        space = Task::SPACE,
+        seeds = [b"task", client.key().as_ref(), &client_profile.task_count.to_le_bytes()],
+        bump
+    )]
+    pub task: Account<'info, Task>,
+    
+    /// CHECK: Escrow account tracking the vault balance
+    #[account(
+        init,
+        payer = client,
+        space = Escrow::SPACE,
+        seeds = [b"escrow", task.key().as_ref()],
+        bump
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    /// Mint the bounty is paid in (SPL Token or Token-2022)
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    /// Escrow token vault, owned by the escrow PDA
+    #[account(
+        init,
+        payer = client,
+        seeds = [b"escrow_vault", task.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = escrow,
+        token::token_program = token_program
+    )]
+    pub escrow_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = client,
+        token::token_program = token_program
+    )]
+    pub client_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// Fee accounting for this mint, created on the mint's first task
+    #[account(
+        init_if_needed,
+        payer = client,
+        space = FeeLedger::SPACE,
+        seeds = [b"fee_ledger", mint.key().as_ref()],
+        bump
+    )]
+    pub fee_ledger: Account<'info, FeeLedger>,
+    
+    /// Treasury-owned vault collecting this mint's fees
+    #[account(
+        init_if_needed,
+        payer = client,
+        seeds = [b"treasury_vault", mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = treasury,
+        token::token_program = token_program
+    )]
+    pub fee_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateTokenTask>,
+    title: String,
+    description_hash: [u8; 32],
+    bounty: u64,
+    deadline: i64,
+    requirements: ClaimRequirements,
+    collateral: Collateral,
+) -> Result<()> {
+    let task = &mut ctx.accounts.task;
+    let client = ctx.accounts.client.key();
+    let mint = ctx.accounts.mint.key();
+    let bump = ctx.bumps.task;
+    
+    // Take the next task id from the client's counter
+    let client_profile = &mut ctx.accounts.client_profile;
+    client_profile.init_if_new(client, ctx.bumps.client_profile)?;
+    let task_id = client_profile.next_task_id()?;
+    
+    // Calculate protocol fee, surcharged for each dispute the client has lost
+    let disputes_lost = client_profile.disputes_lost;
+    let fee = ctx.accounts.config.calculate_fee(bounty, disputes_lost)?;
+    let total_required = bounty
+        .checked_add(fee)
+        .ok_or(AgentTrustError::Overflow)?;
+    
+    // Verify client has enough tokens
+    require!(
+        ctx.accounts.client_token_account.amount >= total_required,
+        AgentTrustError::InsufficientFunds
+    );
+    
+    // Collateral is staked in lamports, so it can't be priced off a token bounty
+    require!(
+        !matches!(collateral, Collateral::BountyBps { .. }),
+        AgentTrustError::InvalidCollateral
+    );
+    
+    // Initialize task
+    task.create(
+        client,
+        task_id,
+        title.clone(),
+        description_hash,
+        bounty,
+        fee,
+        deadline,
+        requirements,
+        collateral,
+        &ctx.accounts.config,
+        bump,
+    )?;
+    task.mint = Some(mint);
+    
+    // Transfer bounty + fee from client to the escrow vault
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.client_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.escrow_vault.to_account_info(),
+                authority: ctx.accounts.client.to_account_info(),
+            },
+        ),
+        total_required,
+        ctx.accounts.mint.decimals,
+    )?;
+    
+    // Token-2022 transfer fees are withheld on the way in, so the vault
+    // may hold less than was sent. The fee is kept whole and the agent's
+    // bounty reflects what actually arrived.
+    ctx.accounts.escrow_vault.reload()?;
+    let received = ctx.accounts.escrow_vault.amount;
+    task.bounty = received
+        .checked_sub(fee)
+        .filter(|net| *net > 0)
+        .ok_or(AgentTrustError::InvalidBounty)?;
+    
+    // Make sure fees in this mint have somewhere to go
+    let fee_vault = ctx.accounts.fee_vault.key();
+    ctx.accounts.fee_ledger.init_if_new(mint, fee_vault, ctx.bumps.fee_ledger);
+    
+    // Initialize escrow
+    let escrow = &mut ctx.accounts.escrow;
+    escrow.task = task.key();
+    escrow.mint = Some(mint);
+    escrow.balance = received;
+    escrow.collateral = 0;
+    escrow.bump = ctx.bumps.escrow;
+    
+    msg!("Task created: {} (#{})", title, task_id);
+    msg!("Mint: {}", mint);
+    msg!("Bounty: {} tokens", task.bounty);
+    msg!("Fee: {} tokens ({} bps)", fee, ctx.accounts.config.client_fee_bps(disputes_lost));
+    msg!("Deadline: {}", deadline);
+    msg!("Agent collateral: {} lamports", task.collateral);
+    
+    Ok(())
+}
@@ -1,29 +1,50 @@
 use anchor_lang::prelude::*;
-use crate::state::{Task, Dispute, get_dispute_seeds};
-use crate::errors::AgentTrustError;
+use anchor_lang::system_program;
+use crate::state::{Task, Dispute, DisputeQueue, ProtocolConfig, ArbitratorRegistry};
+use crate::DisputeParty;
 
 #[derive(Accounts)]
 pub struct DisputeTask<'info> {
+    /// Client or assigned agent opening the dispute
     #[account(mut)]
-    pub client: Signer<'info>,
+    pub initiator: Signer<'info>,
     
     #[account(
-        mut,
-        constraint = task.client == client.key() @ AgentTrustError::NotClient,
-        constraint = task.status == crate::TaskStatus::Submitted @ AgentTrustError::TaskNotSubmitted
+        seeds = [b"config"],
+        bump = config.bump
     )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    #[account(mut)]
     pub task: Account<'info, Task>,
     
     /// CHECK: Dispute PDA will be initialized
     #[account(
         init,
-        payer = client,
+        payer = initiator,
         space = Dispute::SPACE,
-        seeds = get_dispute_seeds(&task.key()),
+        seeds = [b"dispute", task.key().as_ref()],
         bump
     )]
     pub dispute: Account<'info, Dispute>,
     
+    /// Work queue the dispute joins once its evidence period closes
+    #[account(
+        init_if_needed,
+        payer = initiator,
+        space = DisputeQueue::SPACE,
+        seeds = [b"dispute_queue"],
+        bump
+    )]
+    pub dispute_queue: Account<'info, DisputeQueue>,
+    
+    /// Active arbitrators, which must be enough to seat a panel
+    #[account(
+        seeds = [b"arbitrator_registry"],
+        bump = arbitrator_registry.bump
+    )]
+    pub arbitrator_registry: Account<'info, ArbitratorRegistry>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -33,13 +54,41 @@ pub fn handler(ctx: Context<DisputeTask>) -> Result<()> {
     let bump = ctx.bumps.dispute;
     
     // Update task status
-    task.dispute()?;
+    let initiator = ctx.accounts.initiator.key();
+    let initiated_by = task.dispute(initiator)?;
+    
+    // Create dispute; the panel is seated by seat_panel from a later slot's hash
+    let task_key = task.key();
+    let config = &ctx.accounts.config;
+    let bond = config.calculate_dispute_bond(task)?;
+    dispute.create(task_key, initiator, initiated_by, bond, config, bump)?;
+    ctx.accounts.arbitrator_registry.check_can_seat(
+        dispute.panel_size(config),
+        &dispute.panel_exclusions(task.client, task.agent),
+    )?;
+    ctx.accounts.dispute_queue.bump = ctx.bumps.dispute_queue;
     
-    // Create dispute
-    dispute.create(task.key(), bump)?;
+    // Filing bond is held by the dispute account until settlement
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.initiator.to_account_info(),
+                to: dispute.to_account_info(),
+            },
+        ),
+        bond,
+    )?;
     
-    msg!("Dispute filed for task: {}", task.key());
-    msg!("Evidence period: 24 hours");
+    msg!("Dispute filed for task: {}", task_key);
+    match initiated_by {
+        DisputeParty::Client => msg!("Filed by client: {}", initiator),
+        DisputeParty::Agent => msg!("Filed by agent: {}", initiator),
+    }
+    msg!("Filing bond: {} lamports", bond);
+    msg!("Evidence period ends at: {}", dispute.evidence_deadline);
+    msg!("Panel drawn after slot: {}", dispute.seating_slot);
+    msg!("Panel must be seated by: {}", dispute.seating_deadline);
     
     Ok(())
 }
@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{Task, Agent, Escrow, EscrowVault, ProtocolConfig, StakeVault, Treasury, InsurancePool};
+use crate::errors::AgentTrustError;
+
+#[derive(Accounts)]
+pub struct ExpireTask<'info> {
+    /// Anyone can expire an overdue task
+    pub cranker: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    #[account(
+        mut,
+        constraint = task.status == crate::TaskStatus::Claimed @ AgentTrustError::TaskNotClaimed
+    )]
+    pub task: Account<'info, Task>,
+    
+    #[account(
+        mut,
+        constraint = task.agent == Some(agent_account.owner) @ AgentTrustError::NotAssignedAgent
+    )]
+    pub agent_account: Account<'info, Agent>,
+    
+    #[account(
+        mut,
+        seeds = [b"stake_vault", agent_account.key().as_ref()],
+        bump = stake_vault.bump
+    )]
+    pub stake_vault: Account<'info, StakeVault>,
+    
+    /// Treasury receiving its share of the slash
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// Insurance pool receiving its share of the slash
+    #[account(
+        mut,
+        seeds = [b"insurance_pool"],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    /// Client wallet receiving the refund, its share of the slash and escrow rent
+    #[account(
+        mut,
+        constraint = task.client == client.key() @ AgentTrustError::NotClient
+    )]
+    pub client: SystemAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", task.key().as_ref()],
+        bump = escrow.bump,
+        close = client
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    /// Token accounts below are only required for SPL token tasks
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow_vault", task.key().as_ref()],
+        bump
+    )]
+    pub escrow_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        constraint = client_token_account.owner == client.key() @ AgentTrustError::NotClient
+    )]
+    pub client_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler(ctx: Context<ExpireTask>) -> Result<()> {
+    let vault = EscrowVault::from_accounts(
+        &ctx.accounts.escrow_vault,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+    );
+    let task = &mut ctx.accounts.task;
+    let agent_account = &mut ctx.accounts.agent_account;
+    let escrow = &mut ctx.accounts.escrow;
+    let client = ctx.accounts.client.to_account_info();
+    
+    task.expire()?;
+    
+    // Refund bounty + fee to client
+    let refund = escrow.balance;
+    Escrow::pay(
+        escrow,
+        vault.as_ref(),
+        &client,
+        ctx.accounts.client_token_account.as_ref(),
+        refund,
+    )?;
+    
+    if let Some(vault) = vault.as_ref() {
+        Escrow::close_vault(escrow, vault, &client)?;
+    }
+    
+    // Slash the collateral and a share of the agent's stake under the protocol's policy
+    let slash = ctx.accounts.config.calculate_expiry_slash(agent_account, escrow.collateral);
+    agent_account.take_slash(&slash)?;
+    let split = Escrow::pay_slash(
+        escrow,
+        &ctx.accounts.stake_vault,
+        &slash,
+        &ctx.accounts.config,
+        &client,
+        &mut ctx.accounts.treasury,
+        &mut ctx.accounts.insurance_pool,
+    )?;
+    
+    // Collateral above the slash cap goes back to the agent's stake
+    let released = escrow.collateral;
+    agent_account.unlock_collateral(released)?;
+    Escrow::release_collateral(escrow, &ctx.accounts.stake_vault.to_account_info(), released)?;
+    
+    // Count the failed attempt against the agent
+    agent_account.record_attempt();
+    agent_account.finish_task();
+    agent_account.update_reputation_score();
+    
+    msg!("Task expired: {}", task.key());
+    msg!("Refund returned to client: {}", refund);
+    msg!("Agent collateral slashed: {} lamports", slash.collateral);
+    msg!("Agent stake slashed: {} lamports", slash.stake);
+    msg!(
+        "Slash routed: {} to client, {} to treasury, {} to insurance",
+        split.client,
+        split.treasury,
+        split.insurance
+    );
+    msg!("Agent new reputation: {}", agent_account.reputation_score);
+    
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use crate::instructions::resolve_dispute::*;
+use crate::DisputeResolution;
+
+#[derive(Accounts)]
+pub struct FinalizeDefaultJudgment<'info> {
+    /// Anyone can finalize once the evidence period ends with one side silent
+    pub cranker: Signer<'info>,
+    
+    pub settlement: DisputeSettlement<'info>,
+    
+    // remaining_accounts: Arbitrator PDA and stake vault of each panel seat that revealed the ruling
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, FinalizeDefaultJudgment<'info>>) -> Result<()> {
+    let settlement = &mut ctx.accounts.settlement;
+    
+    let ruling = settlement.dispute.default_judgment()?;
+    
+    match ruling {
+        DisputeResolution::ClientWins => msg!("Default judgment: agent submitted no evidence"),
+        _ => msg!("Default judgment: client submitted no evidence"),
+    }
+    
+    settlement.settle(ctx.remaining_accounts)
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::instructions::resolve_dispute::*;
+use crate::utils::move_lamports;
+
+#[derive(Accounts)]
+pub struct FinalizeUnseatedDispute<'info> {
+    /// Anyone can finalize once the seating deadline passes with no panel seated
+    pub cranker: Signer<'info>,
+    
+    pub settlement: DisputeSettlement<'info>,
+    
+    // remaining_accounts: Arbitrator PDA and stake vault of each appealed panel seat that revealed the ruling
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, FinalizeUnseatedDispute<'info>>) -> Result<()> {
+    let settlement = &mut ctx.accounts.settlement;
+    
+    let (party, bond) = settlement.dispute.lapse_seating()?;
+    
+    // The party left waiting on a panel gets its bond back in full
+    let recipient = if party == settlement.client.key() {
+        settlement.client.to_account_info()
+    } else {
+        settlement.agent.to_account_info()
+    };
+    move_lamports(&settlement.dispute.to_account_info(), &recipient, bond)?;
+    
+    msg!("No panel seated by the deadline; bond of {} lamports returned", bond);
+    
+    settlement.settle(ctx.remaining_accounts)
+}
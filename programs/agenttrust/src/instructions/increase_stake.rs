@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::Agent;
+use crate::state::{Agent, StakeVault};
 use crate::errors::AgentTrustError;
 
 #[derive(Accounts)]
@@ -13,6 +13,13 @@ pub struct IncreaseStake<'info> {
     )]
     pub agent: Account<'info, Agent>,
     
+    #[account(
+        mut,
+        seeds = [b"stake_vault", agent.key().as_ref()],
+        bump = stake_vault.bump
+    )]
+    pub stake_vault: Account<'info, StakeVault>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -24,7 +31,13 @@ pub fn handler(
     
     require!(amount > 0, AgentTrustError::InvalidBounty);
     
-    agent.increase_stake(amount);
+    StakeVault::deposit(
+        &ctx.accounts.stake_vault,
+        &ctx.accounts.owner,
+        &ctx.accounts.system_program,
+        amount,
+    )?;
+    agent.increase_stake(amount)?;
     
     // Update reputation with new stake
     agent.update_reputation_score();
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use crate::state::{InsurancePool, ProtocolConfig, Treasury};
+use crate::errors::AgentTrustError;
+use crate::program::Agenttrust;
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    /// Program upgrade authority, becomes the protocol admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = ProtocolConfig::SPACE,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    /// Fee treasury, created alongside the config
+    #[account(
+        init,
+        payer = admin,
+        space = Treasury::SPACE,
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// Insurance pool funded by a share of slashed stake
+    #[account(
+        init,
+        payer = admin,
+        space = InsurancePool::SPACE,
+        seeds = [b"insurance_pool"],
+        bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ AgentTrustError::Unauthorized
+    )]
+    pub program: Program<'info, Agenttrust>,
+    
+    /// Only the upgrade authority can initialize the config, so it can't be front-run
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ AgentTrustError::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeConfig>,
+    treasury: Pubkey,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let admin = ctx.accounts.admin.key();
+    
+    config.initialize(admin, treasury, ctx.bumps.config);
+    config.validate()?;
+    
+    let treasury_account = &mut ctx.accounts.treasury;
+    treasury_account.total_lamport_fees = 0;
+    treasury_account.total_slashed_lamports = 0;
+    treasury_account.total_lamport_withdrawn = 0;
+    treasury_account.total_arbitration_paid = 0;
+    treasury_account.bump = ctx.bumps.treasury;
+    
+    let insurance_pool = &mut ctx.accounts.insurance_pool;
+    insurance_pool.total_deposited = 0;
+    insurance_pool.total_withdrawn = 0;
+    insurance_pool.bump = ctx.bumps.insurance_pool;
+    
+    msg!("Protocol config initialized");
+    msg!("Admin: {}", admin);
+    msg!("Treasury: {}", treasury);
+    msg!("Fee: {} bps", config.fee_bps);
+    
+    Ok(())
+}
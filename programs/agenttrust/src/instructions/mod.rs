@@ -1,21 +1,73 @@
+#![allow(ambiguous_glob_reexports)]
+
+pub mod initialize_config;
+pub mod update_config;
+pub mod withdraw_fees;
+pub mod withdraw_insurance;
+pub mod withdraw_token_fees;
+pub mod add_arbitrator;
+pub mod stake_arbitrator;
+pub mod withdraw_arbitrator_stake;
+pub mod suspend_arbitrator;
+pub mod reinstate_arbitrator;
+pub mod remove_arbitrator;
 pub mod register_agent;
 pub mod create_task;
+pub mod create_token_task;
 pub mod claim_task;
 pub mod submit_task;
 pub mod confirm_task;
+pub mod auto_confirm_task;
+pub mod cancel_task;
+pub mod expire_task;
 pub mod dispute_task;
+pub mod seat_panel;
 pub mod submit_evidence;
+pub mod close_evidence_period;
+pub mod commit_vote;
+pub mod reveal_vote;
+pub mod tally_votes;
+pub mod appeal_dispute;
 pub mod resolve_dispute;
+pub mod finalize_default_judgment;
+pub mod finalize_unseated_dispute;
 pub mod update_reputation;
 pub mod increase_stake;
+pub mod request_unstake;
+pub mod withdraw_stake;
 
+pub use initialize_config::*;
+pub use update_config::*;
+pub use withdraw_fees::*;
+pub use withdraw_insurance::*;
+pub use withdraw_token_fees::*;
+pub use add_arbitrator::*;
+pub use stake_arbitrator::*;
+pub use withdraw_arbitrator_stake::*;
+pub use suspend_arbitrator::*;
+pub use reinstate_arbitrator::*;
+pub use remove_arbitrator::*;
 pub use register_agent::*;
 pub use create_task::*;
+pub use create_token_task::*;
 pub use claim_task::*;
 pub use submit_task::*;
 pub use confirm_task::*;
+pub use auto_confirm_task::*;
+pub use cancel_task::*;
+pub use expire_task::*;
 pub use dispute_task::*;
+pub use seat_panel::*;
 pub use submit_evidence::*;
+pub use close_evidence_period::*;
+pub use commit_vote::*;
+pub use reveal_vote::*;
+pub use tally_votes::*;
+pub use appeal_dispute::*;
 pub use resolve_dispute::*;
+pub use finalize_default_judgment::*;
+pub use finalize_unseated_dispute::*;
 pub use update_reputation::*;
 pub use increase_stake::*;
+pub use request_unstake::*;
+pub use withdraw_stake::*;
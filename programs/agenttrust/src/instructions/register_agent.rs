@@ -1,7 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::{Agent, get_agent_seeds};
-use crate::errors::AgentTrustError;
-use crate::MINIMUM_STAKE_LAMPORTS;
+use crate::state::{Agent, ProtocolConfig, StakeVault};
 
 #[derive(Accounts)]
 #[instruction(name: String)]
@@ -9,15 +7,31 @@ pub struct RegisterAgent<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
     
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
     #[account(
         init,
         payer = owner,
         space = Agent::SPACE,
-        seeds = get_agent_seeds(&owner.key()),
+        seeds = [b"agent", owner.key().as_ref()],
         bump
     )]
     pub agent: Account<'info, Agent>,
     
+    /// Vault holding the agent's staked lamports
+    #[account(
+        init,
+        payer = owner,
+        space = StakeVault::SPACE,
+        seeds = [b"stake_vault", agent.key().as_ref()],
+        bump
+    )]
+    pub stake_vault: Account<'info, StakeVault>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -31,11 +45,18 @@ pub fn handler(
     let bump = ctx.bumps.agent;
     
     // Initialize agent account
-    agent.register(owner, name, initial_stake, bump)?;
+    agent.register(owner, name, initial_stake, &ctx.accounts.config, bump)?;
     
-    // Transfer stake to agent account (stored in the PDA)
-    // Note: In production, you might want a separate stake vault
-    // For simplicity, we're tracking stake in the agent account
+    // Transfer stake into the agent's stake vault
+    let stake_vault = &mut ctx.accounts.stake_vault;
+    stake_vault.owner = agent.key();
+    stake_vault.bump = ctx.bumps.stake_vault;
+    StakeVault::deposit(
+        stake_vault,
+        &ctx.accounts.owner,
+        &ctx.accounts.system_program,
+        initial_stake,
+    )?;
     
     msg!("Agent registered: {}", agent.name);
     msg!("Initial stake: {} lamports", initial_stake);
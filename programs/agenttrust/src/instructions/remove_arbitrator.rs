@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::{Arbitrator, ArbitratorRegistry, ProtocolConfig, StakeVault};
+use crate::errors::AgentTrustError;
+
+#[derive(Accounts)]
+pub struct RemoveArbitrator<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ AgentTrustError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    /// Arbitrator account closed with rent returned to the admin
+    #[account(
+        mut,
+        seeds = [b"arbitrator", arbitrator.authority.as_ref()],
+        bump = arbitrator.bump,
+        close = admin
+    )]
+    pub arbitrator: Account<'info, Arbitrator>,
+    
+    /// Stake vault closed with the remaining stake returned to the arbitrator
+    #[account(
+        mut,
+        seeds = [b"stake_vault", arbitrator.key().as_ref()],
+        bump = stake_vault.bump,
+        close = authority
+    )]
+    pub stake_vault: Account<'info, StakeVault>,
+    
+    /// CHECK: The arbitrator's wallet, receiving its stake
+    #[account(
+        mut,
+        address = arbitrator.authority @ AgentTrustError::Unauthorized
+    )]
+    pub authority: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"arbitrator_registry"],
+        bump = arbitrator_registry.bump
+    )]
+    pub arbitrator_registry: Account<'info, ArbitratorRegistry>,
+}
+
+pub fn handler(ctx: Context<RemoveArbitrator>) -> Result<()> {
+    let arbitrator = &ctx.accounts.arbitrator;
+    
+    // Stake stays slashable until the arbitrator has been suspended and unbonded
+    arbitrator.check_removal(&ctx.accounts.config)?;
+    
+    ctx.accounts.arbitrator_registry.remove(&arbitrator.authority);
+    
+    msg!("Arbitrator removed: {}", arbitrator.authority);
+    msg!("Votes cast: {}", arbitrator.votes_cast);
+    msg!("Stake returned: {} lamports", arbitrator.total_stake);
+    
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::{Agent, ProtocolConfig};
+use crate::errors::AgentTrustError;
+
+#[derive(Accounts)]
+pub struct RequestUnstake<'info> {
+    pub owner: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    #[account(
+        mut,
+        constraint = agent.owner == owner.key() @ AgentTrustError::Unauthorized
+    )]
+    pub agent: Account<'info, Agent>,
+}
+
+pub fn handler(
+    ctx: Context<RequestUnstake>,
+    amount: u64,
+) -> Result<()> {
+    let agent = &mut ctx.accounts.agent;
+    let available_at = Clock::get()?
+        .unix_timestamp
+        .checked_add(ctx.accounts.config.unbonding_period_seconds)
+        .ok_or(AgentTrustError::Overflow)?;
+    
+    agent.request_unstake(amount, available_at)?;
+    
+    // Unbonding stake no longer counts towards reputation
+    agent.update_reputation_score();
+    
+    msg!("Unstake requested: {} lamports", amount);
+    msg!("Total pending: {} lamports", agent.pending_unstake);
+    msg!("Withdrawable at: {}", available_at);
+    msg!("New reputation: {}", agent.reputation_score);
+    
+    Ok(())
+}
@@ -1,76 +1,463 @@
 use anchor_lang::prelude::*;
-use crate::state::{Dispute, Task, Agent};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{Dispute, Task, Agent, ClientProfile, Escrow, EscrowVault, ProtocolConfig, Treasury, FeeLedger, StakeVault, ArbitratorRegistry, InsurancePool, StakedArbitrator, AgentSlash, BPS_DENOMINATOR};
 use crate::errors::AgentTrustError;
-use crate::{DisputeResolution, TRANSACTION_FEE_BPS, MINIMUM_STAKE_LAMPORTS};
+use crate::utils::{apply_bps, move_lamports};
+use crate::{DisputeParty, DisputeResolution, PanelVote};
 
 #[derive(Accounts)]
 pub struct ResolveDispute<'info> {
-    /// The AI/authority resolving the dispute
-    /// In production, this would be a verified AI agent or DAO
-    #[account(mut)]
-    pub authority: Signer<'info>,
+    /// Anyone can carry out a ruling once the panel has reached it
+    #[account(
+        constraint = settlement.dispute.status == crate::DisputeStatus::Resolved @ AgentTrustError::DisputeNotResolved,
+        constraint = settlement.dispute.is_appeal_window_over() @ AgentTrustError::AppealWindowActive
+    )]
+    pub cranker: Signer<'info>,
     
-    #[account(mut)]
+    pub settlement: DisputeSettlement<'info>,
+    
+    // remaining_accounts: Arbitrator PDA and stake vault of each panel seat being rewarded or slashed
+}
+
+/// Accounts that carry out a dispute ruling on the task's escrow, stake and reputation.
+/// Shared by `resolve_dispute` and `finalize_default_judgment`.
+#[derive(Accounts)]
+pub struct DisputeSettlement<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    /// Holds the filing bond and any appeal bond until settlement
+    #[account(
+        mut,
+        seeds = [b"dispute", task.key().as_ref()],
+        bump = dispute.bump,
+        has_one = task @ AgentTrustError::TaskAccountMismatch
+    )]
     pub dispute: Account<'info, Dispute>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = task.status == crate::TaskStatus::Disputed @ AgentTrustError::TaskNotDisputed
+    )]
     pub task: Account<'info, Task>,
     
-    #[account(mut)]
+    /// Agent PDA of the wallet assigned to the task
+    #[account(
+        mut,
+        seeds = [b"agent", task.agent.unwrap_or_default().as_ref()],
+        bump = agent_account.bump
+    )]
     pub agent_account: Account<'info, Agent>,
     
-    /// CHECK: Escrow account
+    #[account(
+        mut,
+        seeds = [b"stake_vault", agent_account.key().as_ref()],
+        bump = stake_vault.bump
+    )]
+    pub stake_vault: Account<'info, StakeVault>,
+    
+    /// Client's profile, which records disputes ruled against the client
+    #[account(
+        mut,
+        seeds = [b"client", task.client.as_ref()],
+        bump = client_profile.bump
+    )]
+    pub client_profile: Account<'info, ClientProfile>,
+    
+    /// Client wallet receiving the refund
+    #[account(
+        mut,
+        constraint = task.client == client.key() @ AgentTrustError::NotClient
+    )]
+    pub client: SystemAccount<'info>,
+    
+    /// Agent wallet receiving the bounty
+    #[account(
+        mut,
+        constraint = task.agent == Some(agent.key()) @ AgentTrustError::NotAssignedAgent
+    )]
+    pub agent: SystemAccount<'info>,
+    
+    /// Escrow holding the task's bounty and fee
     #[account(
         mut,
         seeds = [b"escrow", task.key().as_ref()],
-        bump = escrow.bump
+        bump = escrow.bump,
+        has_one = task @ AgentTrustError::TaskAccountMismatch
     )]
-    pub escrow: Account<'info, crate::instructions::create_task::Escrow>,
+    pub escrow: Account<'info, Escrow>,
+    
+    /// Protocol treasury collecting the fee
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// Insurance pool receiving its share of slashed agent stake
+    #[account(
+        mut,
+        seeds = [b"insurance_pool"],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    /// Arbitrators slashed below the minimum stake leave the panel pool
+    #[account(
+        mut,
+        seeds = [b"arbitrator_registry"],
+        bump = arbitrator_registry.bump
+    )]
+    pub arbitrator_registry: Account<'info, ArbitratorRegistry>,
+    
+    /// Token accounts below are only required for SPL token tasks
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow_vault", task.key().as_ref()],
+        bump
+    )]
+    pub escrow_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        constraint = client_token_account.owner == client.key() @ AgentTrustError::NotClient
+    )]
+    pub client_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        constraint = agent_token_account.owner == agent.key() @ AgentTrustError::NotAssignedAgent
+    )]
+    pub agent_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub fee_ledger: Option<Account<'info, FeeLedger>>,
+    
+    #[account(mut)]
+    pub fee_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Option<Interface<'info, TokenInterface>>,
     
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(
-    ctx: Context<ResolveDispute>,
-    resolution: DisputeResolution,
-) -> Result<()> {
-    let dispute = &mut ctx.accounts.dispute;
-    let task = &mut ctx.accounts.task;
-    let agent_account = &mut ctx.accounts.agent_account;
-    let bounty = task.bounty;
-    
-    // Resolve dispute
-    dispute.resolve(resolution)?;
-    
-    match resolution {
-        DisputeResolution::ClientWins => {
-            // Return bounty to client
-            // Slash agent's stake
-            let slash_amount = MINIMUM_STAKE_LAMPORTS / 2; // Slash 50% of minimum stake
-            agent_account.decrease_stake(slash_amount)?;
-            agent_account.record_dispute_loss();
-            
-            msg!("Dispute resolved: Client wins");
-            msg!("Bounty returned to client: {} lamports", bounty);
-            msg!("Agent slashed: {} lamports", slash_amount);
+impl<'info> DisputeSettlement<'info> {
+    /// Move escrowed funds, slash stake and update reputation per the dispute's ruling.
+    /// `remaining` holds the Arbitrator PDA and stake vault of each seat to reward or slash.
+    pub fn settle(&mut self, remaining: &'info [AccountInfo<'info>]) -> Result<()> {
+        let resolution = self
+            .dispute
+            .resolution
+            .ok_or(AgentTrustError::DisputeNotResolved)?;
+        
+        self.slash_overturned_panel(remaining)?;
+        
+        // Evaluated against the agent's record before this loss is counted
+        let client_share_bps = match resolution {
+            DisputeResolution::ClientWins => BPS_DENOMINATOR,
+            DisputeResolution::AgentWins => 0,
+            DisputeResolution::Split { agent_bps } => BPS_DENOMINATOR - agent_bps as u64,
+        };
+        let slash = self.config.calculate_dispute_slash(
+            &self.task,
+            &self.agent_account,
+            self.escrow.collateral,
+            client_share_bps,
+        );
+        
+        let vault = EscrowVault::from_accounts(
+            &self.escrow_vault,
+            &self.mint,
+            &self.token_program,
+        );
+        let task = &mut self.task;
+        let agent_account = &mut self.agent_account;
+        let escrow = &mut self.escrow;
+        let bounty = task.bounty;
+        let fee = task.fee;
+        
+        // Settle the task according to the ruling
+        task.resolve()?;
+        
+        match resolution {
+            DisputeResolution::ClientWins => {
+                // Return bounty and fee to client
+                let refund = escrow.balance;
+                Escrow::pay(
+                    escrow,
+                    vault.as_ref(),
+                    &self.client.to_account_info(),
+                    self.client_token_account.as_ref(),
+                    refund,
+                )?;
+                agent_account.record_dispute_loss();
+                
+                msg!("Dispute resolved: Client wins");
+                msg!("Refund returned to client: {}", refund);
+            }
+            DisputeResolution::AgentWins => {
+                // Release bounty to agent and fee to treasury
+                Escrow::pay(
+                    escrow,
+                    vault.as_ref(),
+                    &self.agent.to_account_info(),
+                    self.agent_token_account.as_ref(),
+                    bounty,
+                )?;
+                Treasury::collect_fee(
+                    &mut self.treasury,
+                    escrow,
+                    vault.as_ref(),
+                    self.fee_vault.as_mut(),
+                    self.fee_ledger.as_mut(),
+                    fee,
+                )?;
+                
+                agent_account.record_success();
+                self.client_profile.record_dispute_loss();
+                
+                msg!("Dispute resolved: Agent wins");
+                msg!("Agent payment: {}", bounty);
+                msg!("Protocol fee: {}", fee);
+            }
+            DisputeResolution::Split { agent_bps } => {
+                let agent_bps = agent_bps as u64;
+                
+                // Pay the agent its share with the matching share of the fee,
+                // then refund whatever is left to the client
+                let agent_payment = apply_bps(bounty, agent_bps);
+                let fee_share = apply_bps(fee, agent_bps);
+                Escrow::pay(
+                    escrow,
+                    vault.as_ref(),
+                    &self.agent.to_account_info(),
+                    self.agent_token_account.as_ref(),
+                    agent_payment,
+                )?;
+                Treasury::collect_fee(
+                    &mut self.treasury,
+                    escrow,
+                    vault.as_ref(),
+                    self.fee_vault.as_mut(),
+                    self.fee_ledger.as_mut(),
+                    fee_share,
+                )?;
+                let refund = escrow.balance;
+                Escrow::pay(
+                    escrow,
+                    vault.as_ref(),
+                    &self.client.to_account_info(),
+                    self.client_token_account.as_ref(),
+                    refund,
+                )?;
+                agent_account.record_split(agent_bps);
+                
+                msg!("Dispute resolved: Split, {} bps to agent", agent_bps);
+                msg!("Agent payment: {}", agent_payment);
+                msg!("Protocol fee: {}", fee_share);
+                msg!("Refund returned to client: {}", refund);
+            }
         }
-        DisputeResolution::AgentWins => {
-            // Release bounty to agent (minus fee)
-            let fee = (bounty * TRANSACTION_FEE_BPS) / 10_000;
-            let agent_payment = bounty - fee;
-            
-            agent_account.record_success();
+        
+        self.slash_agent(&slash)?;
+        
+        self.settle_filing_bond(remaining, resolution)?;
+        
+        // Overturned: the appeal bond goes back to the appellant. Upheld: it goes to the other party.
+        let dispute = &self.dispute;
+        if let (Some(appellant), Some(upheld)) = (dispute.appellant, dispute.appeal_upheld()) {
+            let to_client = (appellant == self.client.key()) != upheld;
+            let recipient = if to_client {
+                self.client.to_account_info()
+            } else {
+                self.agent.to_account_info()
+            };
+            move_lamports(&dispute.to_account_info(), &recipient, dispute.appeal_bond)?;
             
-            msg!("Dispute resolved: Agent wins");
-            msg!("Agent payment: {} lamports", agent_payment);
-            msg!("Protocol fee: {} lamports", fee);
+            if upheld {
+                msg!("Ruling upheld on appeal; bond of {} lamports forfeited", dispute.appeal_bond);
+            } else {
+                msg!("Ruling overturned on appeal; bond of {} lamports returned", dispute.appeal_bond);
+            }
         }
+        
+        // Update agent reputation
+        let agent_account = &mut self.agent_account;
+        agent_account.finish_task();
+        agent_account.update_reputation_score();
+        
+        msg!("Agent new reputation: {}", agent_account.reputation_score);
+        
+        Ok(())
     }
     
-    // Update agent reputation
-    agent_account.update_reputation_score();
+    /// Carry out the policy slash on the agent's collateral and stake, routed to the client,
+    /// treasury and insurance pool, and return the collateral it leaves to the agent's stake
+    fn slash_agent(&mut self, slash: &AgentSlash) -> Result<()> {
+        self.agent_account.take_slash(slash)?;
+        let split = Escrow::pay_slash(
+            &mut self.escrow,
+            &self.stake_vault,
+            slash,
+            &self.config,
+            &self.client.to_account_info(),
+            &mut self.treasury,
+            &mut self.insurance_pool,
+        )?;
+        
+        let released = self.escrow.collateral;
+        self.agent_account.unlock_collateral(released)?;
+        Escrow::release_collateral(&mut self.escrow, &self.stake_vault.to_account_info(), released)?;
+        
+        msg!("Agent collateral slashed: {} lamports", slash.collateral);
+        msg!("Agent stake slashed: {} lamports", slash.stake);
+        msg!(
+            "Slash routed: {} to client, {} to treasury, {} to insurance",
+            split.client,
+            split.treasury,
+            split.insurance
+        );
+        
+        Ok(())
+    }
     
-    msg!("Agent new reputation: {}", agent_account.reputation_score);
+    /// Return the filing bond to the initiator in proportion to the ruling and pay the
+    /// panel. The arbitration fee doesn't depend on the ruling: it comes out of the part
+    /// of the bond the initiator forfeits, topped up from the treasury's fees, and the
+    /// rest of the forfeited part goes to the counterparty.
+    fn settle_filing_bond(
+        &mut self,
+        remaining: &'info [AccountInfo<'info>],
+        ruling: DisputeResolution,
+    ) -> Result<()> {
+        let filing_bond = self.dispute.filing_bond;
+        let refund = apply_bps(filing_bond, self.dispute.filing_bond_refund_bps());
+        let forfeit = filing_bond - refund;
+        
+        let fee = apply_bps(filing_bond, self.config.arbitrator_fee_bps)
+            .min(forfeit.saturating_add(self.treasury.available_lamports()));
+        let paid = self.reward_panel(remaining, ruling, fee)?;
+        
+        // The panel was paid out of the dispute account; the treasury covers what the
+        // forfeited bond can't
+        let from_treasury = paid.saturating_sub(forfeit);
+        self.treasury.record_arbitration_payment(from_treasury)?;
+        move_lamports(
+            &self.treasury.to_account_info(),
+            &self.dispute.to_account_info(),
+            from_treasury,
+        )?;
+        let forfeit = forfeit - (paid - from_treasury);
+        
+        let (initiator, counterparty) = match self.dispute.initiated_by {
+            DisputeParty::Client => (self.client.to_account_info(), self.agent.to_account_info()),
+            DisputeParty::Agent => (self.agent.to_account_info(), self.client.to_account_info()),
+        };
+        move_lamports(&self.dispute.to_account_info(), &initiator, refund)?;
+        move_lamports(&self.dispute.to_account_info(), &counterparty, forfeit)?;
+        
+        msg!("Filing bond returned: {} lamports", refund);
+        msg!("Filing bond forfeited: {} lamports", forfeit);
+        msg!("Arbitration fee paid: {} lamports ({} from the treasury)", paid, from_treasury);
+        
+        Ok(())
+    }
     
-    Ok(())
+    /// Split `fee` equally among the current panel seats that revealed the final ruling,
+    /// crediting each share to the arbitrator's stake from the dispute account.
+    /// Returns the total paid.
+    fn reward_panel(
+        &mut self,
+        remaining: &'info [AccountInfo<'info>],
+        ruling: DisputeResolution,
+        fee: u64,
+    ) -> Result<u64> {
+        let dispute = &self.dispute;
+        let majority: Vec<Pubkey> = dispute
+            .panel
+            .iter()
+            .zip(dispute.votes.iter())
+            .filter(|(_, vote)| **vote == PanelVote::Revealed { resolution: ruling })
+            .map(|(authority, _)| *authority)
+            .collect();
+        
+        // Default judgments are reached without a panel vote
+        if majority.is_empty() {
+            return Ok(0);
+        }
+        
+        let share = fee / majority.len() as u64;
+        let mut paid = 0;
+        
+        for authority in majority.iter() {
+            // A removed arbitrator's share is not paid
+            let mut seat = match StakedArbitrator::load(remaining, authority)? {
+                Some(seat) => seat,
+                None => continue,
+            };
+            
+            seat.reward(&dispute.to_account_info(), share)?;
+            seat.arbitrator.votes_with_majority = seat
+                .arbitrator
+                .votes_with_majority
+                .checked_add(1)
+                .ok_or(AgentTrustError::Overflow)?;
+            seat.exit()?;
+            paid += share;
+            
+            msg!("Arbitrator rewarded: {} ({} lamports)", authority, share);
+        }
+        
+        Ok(paid)
+    }
+    
+    /// When an appeal overturns the ruling, slash every prior panel seat that voted for it
+    fn slash_overturned_panel(&mut self, remaining: &'info [AccountInfo<'info>]) -> Result<()> {
+        if self.dispute.appeal_upheld() != Some(false) {
+            return Ok(());
+        }
+        
+        let dispute = &self.dispute;
+        let appealed = dispute
+            .appealed_ruling
+            .ok_or(AgentTrustError::DisputeNotResolved)?;
+        let overturned: Vec<Pubkey> = dispute
+            .prior_panel
+            .iter()
+            .zip(dispute.prior_votes.iter())
+            .filter(|(_, vote)| **vote == PanelVote::Revealed { resolution: appealed })
+            .map(|(authority, _)| *authority)
+            .collect();
+        
+        for authority in overturned.iter() {
+            let mut seat = match StakedArbitrator::load(remaining, authority)? {
+                Some(seat) => seat,
+                None => continue,
+            };
+            
+            let slashed = seat.slash(&self.treasury.to_account_info(), &self.config)?;
+            self.treasury.record_slash(slashed)?;
+            seat.arbitrator.overturned_votes = seat
+                .arbitrator
+                .overturned_votes
+                .checked_add(1)
+                .ok_or(AgentTrustError::Overflow)?;
+            self.arbitrator_registry.sync(&seat.arbitrator, &self.config)?;
+            seat.exit()?;
+            
+            msg!("Arbitrator overturned on appeal: {} (slashed {} lamports)", authority, slashed);
+        }
+        
+        Ok(())
+    }
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ResolveDispute<'info>>) -> Result<()> {
+    ctx.accounts.settlement.settle(ctx.remaining_accounts)
 }
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::state::{Arbitrator, Dispute, ProtocolConfig};
+use crate::DisputeResolution;
+
+#[derive(Accounts)]
+pub struct RevealVote<'info> {
+    /// Panel arbitrator opening a sealed vote
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"arbitrator", authority.key().as_ref()],
+        bump = arbitrator.bump
+    )]
+    pub arbitrator: Account<'info, Arbitrator>,
+    
+    #[account(mut)]
+    pub dispute: Account<'info, Dispute>,
+}
+
+pub fn handler(
+    ctx: Context<RevealVote>,
+    resolution: DisputeResolution,
+    salt: [u8; 32],
+) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    
+    // Round and latency are read before the reveal can open a fresh round
+    let round = dispute.round;
+    let latency = Clock::get()?.unix_timestamp - dispute.voting_opened_at;
+    
+    dispute.reveal_vote(ctx.accounts.authority.key(), resolution, salt, &ctx.accounts.config)?;
+    ctx.accounts.arbitrator.record_vote(round, latency)?;
+    
+    msg!("Vote revealed by arbitrator: {}", ctx.accounts.authority.key());
+    msg!(
+        "Tally: {} client, {} agent, {} split of {} seats",
+        dispute.client_votes,
+        dispute.agent_votes,
+        dispute.split_votes,
+        dispute.panel.len()
+    );
+    
+    if dispute.resolution.is_some() && dispute.majority().is_some() {
+        msg!("Panel majority reached; dispute resolved");
+    } else if dispute.resolution.is_some() {
+        msg!("Panel can't reach a majority; fallback ruling applied");
+    } else if dispute.status == crate::DisputeStatus::VoteCommit {
+        msg!("No majority; voting round {} opened", dispute.round);
+    }
+    
+    Ok(())
+}
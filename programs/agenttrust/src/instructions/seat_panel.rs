@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use crate::state::{Task, Dispute, ProtocolConfig, ArbitratorRegistry};
+use crate::utils::slot_hash_after;
+
+#[derive(Accounts)]
+pub struct SeatPanel<'info> {
+    /// Anyone can seat a panel once the slot after filing (or appeal) is hashed
+    pub cranker: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    pub task: Account<'info, Task>,
+    
+    #[account(
+        mut,
+        seeds = [b"dispute", task.key().as_ref()],
+        bump = dispute.bump
+    )]
+    pub dispute: Account<'info, Dispute>,
+    
+    /// Active arbitrators the panel is drawn from
+    #[account(
+        seeds = [b"arbitrator_registry"],
+        bump = arbitrator_registry.bump
+    )]
+    pub arbitrator_registry: Account<'info, ArbitratorRegistry>,
+    
+    /// CHECK: SlotHashes sysvar, the source of the draw's seed
+    #[account(address = sysvar::slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<SeatPanel>) -> Result<()> {
+    let task = &ctx.accounts.task;
+    let dispute = &mut ctx.accounts.dispute;
+    
+    // The filer signs before the next slot's hash exists, so it can't grind the draw
+    let hash = match slot_hash_after(&ctx.accounts.slot_hashes, dispute.seating_slot)? {
+        Some(hash) => hash,
+        None => {
+            dispute.restart_seating()?;
+            msg!("Seating slot expired; panel will be drawn after slot {}", dispute.seating_slot);
+            return Ok(());
+        }
+    };
+    
+    let task_key = task.key();
+    let excluded = dispute.panel_exclusions(task.client, task.agent);
+    let panel = ctx.accounts.arbitrator_registry.select_panel(
+        dispute.panel_size(&ctx.accounts.config),
+        &[task_key.as_ref(), &hash].concat(),
+        &excluded,
+    )?;
+    dispute.seat(panel)?;
+    
+    msg!("Panel seated for dispute: {}", dispute.key());
+    msg!("Panel: {:?}", dispute.panel);
+    
+    Ok(())
+}
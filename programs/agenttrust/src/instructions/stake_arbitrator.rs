@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::state::{Arbitrator, ArbitratorRegistry, ProtocolConfig, StakeVault};
+use crate::errors::AgentTrustError;
+
+#[derive(Accounts)]
+pub struct StakeArbitrator<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"arbitrator", authority.key().as_ref()],
+        bump = arbitrator.bump
+    )]
+    pub arbitrator: Account<'info, Arbitrator>,
+    
+    #[account(
+        mut,
+        seeds = [b"stake_vault", arbitrator.key().as_ref()],
+        bump = stake_vault.bump
+    )]
+    pub stake_vault: Account<'info, StakeVault>,
+    
+    #[account(
+        mut,
+        seeds = [b"arbitrator_registry"],
+        bump = arbitrator_registry.bump
+    )]
+    pub arbitrator_registry: Account<'info, ArbitratorRegistry>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<StakeArbitrator>,
+    amount: u64,
+) -> Result<()> {
+    let arbitrator = &mut ctx.accounts.arbitrator;
+    
+    require!(amount > 0, AgentTrustError::InsufficientStake);
+    
+    StakeVault::deposit(
+        &ctx.accounts.stake_vault,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+        amount,
+    )?;
+    arbitrator.total_stake = arbitrator
+        .total_stake
+        .checked_add(amount)
+        .ok_or(AgentTrustError::Overflow)?;
+    
+    // Joins the panel pool once the stake reaches the minimum
+    ctx.accounts.arbitrator_registry.sync(arbitrator, &ctx.accounts.config)?;
+    
+    msg!("Arbitrator stake increased by: {} lamports", amount);
+    msg!("New total stake: {} lamports", arbitrator.total_stake);
+    
+    Ok(())
+}
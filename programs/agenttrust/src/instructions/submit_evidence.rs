@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
-use crate::state::{Dispute, Task};
+use crate::state::{Dispute, Evidence, Task};
 use crate::errors::AgentTrustError;
+use crate::EvidenceType;
 
 #[derive(Accounts)]
 pub struct SubmitEvidence<'info> {
@@ -14,11 +15,24 @@ pub struct SubmitEvidence<'info> {
     
     #[account(mut)]
     pub dispute: Account<'info, Dispute>,
+    
+    /// Next entry in the dispute's evidence log
+    #[account(
+        init,
+        payer = participant,
+        space = Evidence::SPACE,
+        seeds = [b"evidence", dispute.key().as_ref(), dispute.evidence_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub evidence: Account<'info, Evidence>,
+    
+    pub system_program: Program<'info, System>,
 }
 
 pub fn handler(
     ctx: Context<SubmitEvidence>,
     evidence_hash: [u8; 32],
+    content_type: EvidenceType,
 ) -> Result<()> {
     let dispute = &mut ctx.accounts.dispute;
     let task = &ctx.accounts.task;
@@ -33,12 +47,20 @@ pub fn handler(
         AgentTrustError::Unauthorized
     );
     
-    dispute.submit_evidence(evidence_hash, is_client)?;
+    let sequence = dispute.record_evidence(is_client)?;
+    ctx.accounts.evidence.record(
+        dispute.key(),
+        sequence,
+        participant,
+        evidence_hash,
+        content_type,
+        ctx.bumps.evidence,
+    )?;
     
     if is_client {
-        msg!("Client submitted evidence");
+        msg!("Client submitted evidence #{}", sequence);
     } else {
-        msg!("Agent submitted evidence");
+        msg!("Agent submitted evidence #{}", sequence);
     }
     
     Ok(())
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::{Arbitrator, ArbitratorRegistry, ProtocolConfig};
+use crate::errors::AgentTrustError;
+
+#[derive(Accounts)]
+pub struct SuspendArbitrator<'info> {
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ AgentTrustError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"arbitrator", arbitrator.authority.as_ref()],
+        bump = arbitrator.bump
+    )]
+    pub arbitrator: Account<'info, Arbitrator>,
+    
+    #[account(
+        mut,
+        seeds = [b"arbitrator_registry"],
+        bump = arbitrator_registry.bump
+    )]
+    pub arbitrator_registry: Account<'info, ArbitratorRegistry>,
+}
+
+pub fn handler(ctx: Context<SuspendArbitrator>) -> Result<()> {
+    let arbitrator = &mut ctx.accounts.arbitrator;
+    
+    arbitrator.suspend()?;
+    ctx.accounts.arbitrator_registry.remove(&arbitrator.authority);
+    
+    msg!("Arbitrator suspended: {}", arbitrator.authority);
+    
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::{ArbitratorRegistry, Dispute, ProtocolConfig, StakedArbitrator, Treasury};
+
+#[derive(Accounts)]
+pub struct TallyVotes<'info> {
+    /// Anyone can close a commit or reveal phase once its deadline passes
+    pub cranker: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    #[account(mut)]
+    pub dispute: Account<'info, Dispute>,
+    
+    #[account(
+        mut,
+        seeds = [b"arbitrator_registry"],
+        bump = arbitrator_registry.bump
+    )]
+    pub arbitrator_registry: Account<'info, ArbitratorRegistry>,
+    
+    /// Receives stake slashed from arbitrators that missed a deadline
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    // remaining_accounts: Arbitrator PDA and stake vault of each seat that missed a deadline
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, TallyVotes<'info>>) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let registry = &mut ctx.accounts.arbitrator_registry;
+    let treasury = &mut ctx.accounts.treasury;
+    
+    let forfeited = dispute.close_phase(&ctx.accounts.config)?;
+    
+    // Slash and suspend each arbitrator that missed the commit or reveal deadline
+    for authority in forfeited.iter() {
+        registry.remove(authority);
+        
+        // An arbitrator removed by the admin has nothing left to penalize
+        let mut seat = match StakedArbitrator::load(ctx.remaining_accounts, authority)? {
+            Some(seat) => seat,
+            None => continue,
+        };
+        
+        let slashed = seat.slash(&treasury.to_account_info(), &ctx.accounts.config)?;
+        treasury.record_slash(slashed)?;
+        seat.arbitrator.record_missed_deadline()?;
+        seat.exit()?;
+        
+        msg!("Arbitrator forfeited seat: {} (slashed {} lamports)", authority, slashed);
+    }
+    
+    match dispute.resolution {
+        Some(_) if dispute.majority().is_some() => msg!("Panel majority reached; dispute resolved"),
+        Some(_) => msg!("Panel can't reach a majority; fallback ruling applied"),
+        None if dispute.status == crate::DisputeStatus::VoteCommit => {
+            msg!("No majority; voting round {} opened", dispute.round)
+        }
+        None => msg!("Commit phase closed; reveals open until {}", dispute.reveal_deadline),
+    }
+    
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProtocolConfig, ConfigUpdate};
+use crate::errors::AgentTrustError;
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ AgentTrustError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateConfig>,
+    update: ConfigUpdate,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    
+    config.update(update)?;
+    
+    msg!("Protocol config updated");
+    msg!("Admin: {}", config.admin);
+    msg!("Treasury: {}", config.treasury);
+    msg!("Fee: {} bps", config.fee_bps);
+    msg!("Minimum stake: {} lamports", config.min_stake_lamports);
+    msg!("Dispute window: {} seconds", config.dispute_window_seconds);
+    
+    Ok(())
+}
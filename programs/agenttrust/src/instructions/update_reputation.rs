@@ -1,6 +1,5 @@
 use anchor_lang::prelude::*;
 use crate::state::Agent;
-use crate::errors::AgentTrustError;
 
 #[derive(Accounts)]
 pub struct UpdateReputation<'info> {
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::state::{Arbitrator, ArbitratorRegistry, ProtocolConfig, StakeVault};
+use crate::errors::AgentTrustError;
+
+#[derive(Accounts)]
+pub struct WithdrawArbitratorStake<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"arbitrator", authority.key().as_ref()],
+        bump = arbitrator.bump
+    )]
+    pub arbitrator: Account<'info, Arbitrator>,
+    
+    #[account(
+        mut,
+        seeds = [b"stake_vault", arbitrator.key().as_ref()],
+        bump = stake_vault.bump
+    )]
+    pub stake_vault: Account<'info, StakeVault>,
+    
+    #[account(
+        mut,
+        seeds = [b"arbitrator_registry"],
+        bump = arbitrator_registry.bump
+    )]
+    pub arbitrator_registry: Account<'info, ArbitratorRegistry>,
+}
+
+pub fn handler(
+    ctx: Context<WithdrawArbitratorStake>,
+    amount: u64,
+) -> Result<()> {
+    let arbitrator = &mut ctx.accounts.arbitrator;
+    
+    require!(amount > 0, AgentTrustError::InsufficientStake);
+    
+    // Active arbitrators keep the minimum; suspended ones wait out the unbonding period
+    arbitrator.check_withdrawal(amount, &ctx.accounts.config)?;
+    
+    StakeVault::withdraw(
+        &ctx.accounts.stake_vault,
+        &ctx.accounts.authority.to_account_info(),
+        amount,
+    )?;
+    arbitrator.total_stake -= amount;
+    
+    ctx.accounts.arbitrator_registry.sync(arbitrator, &ctx.accounts.config)?;
+    
+    msg!("Arbitrator stake withdrawn: {} lamports", amount);
+    msg!("Remaining stake: {} lamports", arbitrator.total_stake);
+    
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProtocolConfig, Treasury};
+use crate::errors::AgentTrustError;
+use crate::utils::move_lamports;
+
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ AgentTrustError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// CHECK: Withdrawal destination configured by the protocol admin
+    #[account(
+        mut,
+        address = config.treasury @ AgentTrustError::Unauthorized
+    )]
+    pub destination: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<WithdrawFees>,
+    amount: u64,
+) -> Result<()> {
+    let treasury = &mut ctx.accounts.treasury;
+    
+    require!(amount > 0, AgentTrustError::InsufficientFunds);
+    
+    // Only collected fees and slashed stake can leave; the treasury's rent stays behind
+    treasury.record_withdrawal(amount)?;
+    
+    let destination = ctx.accounts.destination.to_account_info();
+    move_lamports(&treasury.to_account_info(), &destination, amount)?;
+    
+    msg!("Withdrew {} lamports from the treasury to {}", amount, destination.key());
+    msg!("Total fees collected: {} lamports", treasury.total_lamport_fees);
+    msg!("Total stake slashed: {} lamports", treasury.total_slashed_lamports);
+    msg!("Total withdrawn: {} lamports", treasury.total_lamport_withdrawn);
+    
+    Ok(())
+}
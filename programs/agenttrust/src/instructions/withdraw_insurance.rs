@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::{InsurancePool, ProtocolConfig};
+use crate::errors::AgentTrustError;
+use crate::utils::move_lamports;
+
+#[derive(Accounts)]
+pub struct WithdrawInsurance<'info> {
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ AgentTrustError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"insurance_pool"],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    /// CHECK: Payout recipient chosen by the protocol admin, e.g. a client being compensated
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<WithdrawInsurance>,
+    amount: u64,
+) -> Result<()> {
+    let insurance_pool = &mut ctx.accounts.insurance_pool;
+    
+    require!(amount > 0, AgentTrustError::InsufficientFunds);
+    
+    // Only deposited slashes can leave; the pool's rent stays behind
+    insurance_pool.record_withdrawal(amount)?;
+    
+    let destination = ctx.accounts.destination.to_account_info();
+    move_lamports(&insurance_pool.to_account_info(), &destination, amount)?;
+    
+    msg!("Paid {} lamports from the insurance pool to {}", amount, destination.key());
+    msg!("Total deposited: {} lamports", insurance_pool.total_deposited);
+    msg!("Total withdrawn: {} lamports", insurance_pool.total_withdrawn);
+    
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::state::{Agent, StakeVault};
+use crate::errors::AgentTrustError;
+
+#[derive(Accounts)]
+pub struct WithdrawStake<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = agent.owner == owner.key() @ AgentTrustError::Unauthorized
+    )]
+    pub agent: Account<'info, Agent>,
+    
+    #[account(
+        mut,
+        seeds = [b"stake_vault", agent.key().as_ref()],
+        bump = stake_vault.bump
+    )]
+    pub stake_vault: Account<'info, StakeVault>,
+}
+
+pub fn handler(ctx: Context<WithdrawStake>) -> Result<()> {
+    let agent = &mut ctx.accounts.agent;
+    
+    let amount = agent.complete_unstake()?;
+    
+    StakeVault::withdraw(
+        &ctx.accounts.stake_vault,
+        &ctx.accounts.owner.to_account_info(),
+        amount,
+    )?;
+    
+    agent.update_reputation_score();
+    
+    msg!("Stake withdrawn: {} lamports", amount);
+    msg!("Remaining stake: {} lamports", agent.total_stake);
+    msg!("New reputation: {}", agent.reputation_score);
+    
+    Ok(())
+}
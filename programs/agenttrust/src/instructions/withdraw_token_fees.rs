@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::{ProtocolConfig, Treasury, FeeLedger};
+use crate::errors::AgentTrustError;
+
+#[derive(Accounts)]
+pub struct WithdrawTokenFees<'info> {
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ AgentTrustError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        mut,
+        seeds = [b"fee_ledger", mint.key().as_ref()],
+        bump = fee_ledger.bump,
+        has_one = mint @ AgentTrustError::InvalidMint
+    )]
+    pub fee_ledger: Account<'info, FeeLedger>,
+    
+    #[account(
+        mut,
+        address = fee_ledger.vault @ AgentTrustError::InvalidMint
+    )]
+    pub fee_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Token account of the configured withdrawal destination
+    #[account(
+        mut,
+        token::mint = mint,
+        constraint = destination.owner == config.treasury @ AgentTrustError::Unauthorized
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(
+    ctx: Context<WithdrawTokenFees>,
+    amount: u64,
+) -> Result<()> {
+    let fee_ledger = &mut ctx.accounts.fee_ledger;
+    
+    require!(amount > 0, AgentTrustError::InsufficientFunds);
+    
+    fee_ledger.record_withdrawal(amount)?;
+    
+    let bump = [ctx.accounts.treasury.bump];
+    let signer_seeds: &[&[&[u8]]] = &[&[b"treasury", &bump]];
+    
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.fee_vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.treasury.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+    
+    msg!("Withdrew {} tokens in fees to {}", amount, ctx.accounts.destination.key());
+    msg!("Mint: {}", fee_ledger.mint);
+    msg!("Total collected: {}", fee_ledger.total_fees);
+    msg!("Total withdrawn: {}", fee_ledger.total_withdrawn);
+    
+    Ok(())
+}
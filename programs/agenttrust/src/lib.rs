@@ -3,8 +3,10 @@ use anchor_lang::prelude::*;
 pub mod state;
 pub mod instructions;
 pub mod errors;
+pub mod utils;
 
 use instructions::*;
+use state::{ClaimRequirements, ConfigUpdate};
 
 declare_id!("AGENTtrust111111111111111111111111111111111");
 
@@ -12,6 +14,76 @@ declare_id!("AGENTtrust111111111111111111111111111111111");
 pub mod agenttrust {
     use super::*;
 
+    // Protocol configuration
+    pub fn initialize_config(
+        ctx: Context<InitializeConfig>,
+        treasury: Pubkey,
+    ) -> Result<()> {
+        instructions::initialize_config::handler(ctx, treasury)
+    }
+
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        update: ConfigUpdate,
+    ) -> Result<()> {
+        instructions::update_config::handler(ctx, update)
+    }
+
+    pub fn withdraw_fees(
+        ctx: Context<WithdrawFees>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_fees::handler(ctx, amount)
+    }
+
+    pub fn withdraw_insurance(
+        ctx: Context<WithdrawInsurance>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_insurance::handler(ctx, amount)
+    }
+
+    pub fn withdraw_token_fees(
+        ctx: Context<WithdrawTokenFees>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_token_fees::handler(ctx, amount)
+    }
+
+    // Arbitrator registry
+    pub fn add_arbitrator(
+        ctx: Context<AddArbitrator>,
+        authority: Pubkey,
+    ) -> Result<()> {
+        instructions::add_arbitrator::handler(ctx, authority)
+    }
+
+    pub fn stake_arbitrator(
+        ctx: Context<StakeArbitrator>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::stake_arbitrator::handler(ctx, amount)
+    }
+
+    pub fn withdraw_arbitrator_stake(
+        ctx: Context<WithdrawArbitratorStake>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_arbitrator_stake::handler(ctx, amount)
+    }
+
+    pub fn suspend_arbitrator(ctx: Context<SuspendArbitrator>) -> Result<()> {
+        instructions::suspend_arbitrator::handler(ctx)
+    }
+
+    pub fn reinstate_arbitrator(ctx: Context<ReinstateArbitrator>) -> Result<()> {
+        instructions::reinstate_arbitrator::handler(ctx)
+    }
+
+    pub fn remove_arbitrator(ctx: Context<RemoveArbitrator>) -> Result<()> {
+        instructions::remove_arbitrator::handler(ctx)
+    }
+
     // Agent management
     pub fn register_agent(
         ctx: Context<RegisterAgent>,
@@ -28,6 +100,17 @@ pub mod agenttrust {
         instructions::increase_stake::handler(ctx, amount)
     }
 
+    pub fn request_unstake(
+        ctx: Context<RequestUnstake>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::request_unstake::handler(ctx, amount)
+    }
+
+    pub fn withdraw_stake(ctx: Context<WithdrawStake>) -> Result<()> {
+        instructions::withdraw_stake::handler(ctx)
+    }
+
     // Task lifecycle
     pub fn create_task(
         ctx: Context<CreateTask>,
@@ -35,8 +118,22 @@ pub mod agenttrust {
         description_hash: [u8; 32],
         bounty: u64,
         deadline: i64,
+        requirements: ClaimRequirements,
+        collateral: Collateral,
     ) -> Result<()> {
-        instructions::create_task::handler(ctx, title, description_hash, bounty, deadline)
+        instructions::create_task::handler(ctx, title, description_hash, bounty, deadline, requirements, collateral)
+    }
+
+    pub fn create_token_task(
+        ctx: Context<CreateTokenTask>,
+        title: String,
+        description_hash: [u8; 32],
+        bounty: u64,
+        deadline: i64,
+        requirements: ClaimRequirements,
+        collateral: Collateral,
+    ) -> Result<()> {
+        instructions::create_token_task::handler(ctx, title, description_hash, bounty, deadline, requirements, collateral)
     }
 
     pub fn claim_task(ctx: Context<ClaimTask>) -> Result<()> {
@@ -54,23 +151,78 @@ pub mod agenttrust {
         instructions::confirm_task::handler(ctx)
     }
 
+    pub fn auto_confirm_task(ctx: Context<AutoConfirmTask>) -> Result<()> {
+        instructions::auto_confirm_task::handler(ctx)
+    }
+
+    pub fn cancel_task(ctx: Context<CancelTask>) -> Result<()> {
+        instructions::cancel_task::handler(ctx)
+    }
+
+    pub fn expire_task(ctx: Context<ExpireTask>) -> Result<()> {
+        instructions::expire_task::handler(ctx)
+    }
+
     // Dispute resolution
     pub fn dispute_task(ctx: Context<DisputeTask>) -> Result<()> {
         instructions::dispute_task::handler(ctx)
     }
 
+    pub fn seat_panel(ctx: Context<SeatPanel>) -> Result<()> {
+        instructions::seat_panel::handler(ctx)
+    }
+
     pub fn submit_evidence(
         ctx: Context<SubmitEvidence>,
         evidence_hash: [u8; 32],
+        content_type: EvidenceType,
     ) -> Result<()> {
-        instructions::submit_evidence::handler(ctx, evidence_hash)
+        instructions::submit_evidence::handler(ctx, evidence_hash, content_type)
+    }
+
+    pub fn close_evidence_period(ctx: Context<CloseEvidencePeriod>) -> Result<()> {
+        instructions::close_evidence_period::handler(ctx)
     }
 
-    pub fn resolve_dispute(
-        ctx: Context<ResolveDispute>,
+    pub fn commit_vote(
+        ctx: Context<CommitVote>,
+        commitment: [u8; 32],
+    ) -> Result<()> {
+        instructions::commit_vote::handler(ctx, commitment)
+    }
+
+    pub fn reveal_vote(
+        ctx: Context<RevealVote>,
         resolution: DisputeResolution,
+        salt: [u8; 32],
     ) -> Result<()> {
-        instructions::resolve_dispute::handler(ctx, resolution)
+        instructions::reveal_vote::handler(ctx, resolution, salt)
+    }
+
+    pub fn tally_votes<'info>(ctx: Context<'_, '_, 'info, 'info, TallyVotes<'info>>) -> Result<()> {
+        instructions::tally_votes::handler(ctx)
+    }
+
+    pub fn appeal_dispute(ctx: Context<AppealDispute>) -> Result<()> {
+        instructions::appeal_dispute::handler(ctx)
+    }
+
+    pub fn resolve_dispute<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ResolveDispute<'info>>,
+    ) -> Result<()> {
+        instructions::resolve_dispute::handler(ctx)
+    }
+
+    pub fn finalize_default_judgment<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinalizeDefaultJudgment<'info>>,
+    ) -> Result<()> {
+        instructions::finalize_default_judgment::handler(ctx)
+    }
+
+    pub fn finalize_unseated_dispute<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinalizeUnseatedDispute<'info>>,
+    ) -> Result<()> {
+        instructions::finalize_unseated_dispute::handler(ctx)
     }
 
     // Reputation
@@ -86,6 +238,9 @@ pub enum TaskStatus {
     Submitted,
     Confirmed,
     Disputed,
+    Cancelled,
+    Expired,
+    Resolved,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
@@ -93,17 +248,92 @@ pub enum DisputeStatus {
     EvidencePeriod,
     JudgmentPending,
     Resolved,
+    VoteCommit,
+    VoteReveal,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum DisputeParty {
+    Client,
+    Agent,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
 pub enum DisputeResolution {
     ClientWins,
     AgentWins,
+    /// Agent is paid `agent_bps` of the bounty and the client refunded the rest
+    Split { agent_bps: u16 },
+}
+
+/// Collateral an agent locks from its stake when claiming a task
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum Collateral {
+    None,
+    /// A fixed amount of lamports
+    Fixed { lamports: u64 },
+    /// A share of the bounty in basis points (lamport bounties only)
+    BountyBps { bps: u16 },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum EvidenceType {
+    Document,
+    Deliverable,
+    Link,
+    Statement,
+    Other,
 }
 
-// Constants
+/// A panel seat's progress through commit-reveal voting
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PanelVote {
+    Pending,
+    Committed { commitment: [u8; 32] },
+    Revealed { resolution: DisputeResolution },
+    Forfeited,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ArbitratorStatus {
+    Active,
+    Suspended,
+}
+
+// Constants (defaults for ProtocolConfig; name/title lengths are also the account space caps)
 pub const TRANSACTION_FEE_BPS: u64 = 100; // 1% = 100 basis points
 pub const MINIMUM_STAKE_LAMPORTS: u64 = 20_000_000; // 0.02 SOL
 pub const DISPUTE_WINDOW_SECONDS: i64 = 24 * 60 * 60; // 24 hours
+pub const UNBONDING_PERIOD_SECONDS: i64 = 7 * 24 * 60 * 60; // 7 days
+pub const EXPIRY_SLASH_BPS: u64 = 1_000; // 10% of stake
+pub const REVIEW_WINDOW_SECONDS: i64 = 3 * 24 * 60 * 60; // 3 days
+pub const PANEL_SIZE: u8 = 3;
+pub const COMMIT_WINDOW_SECONDS: i64 = 24 * 60 * 60; // 24 hours
+pub const REVEAL_WINDOW_SECONDS: i64 = 24 * 60 * 60; // 24 hours
+pub const APPEAL_WINDOW_SECONDS: i64 = 2 * 24 * 60 * 60; // 48 hours
+pub const SEATING_WINDOW_SECONDS: i64 = 24 * 60 * 60; // 24 hours
+pub const APPEAL_BOND_LAMPORTS: u64 = 100_000_000; // 0.1 SOL
+pub const APPEAL_PANEL_SIZE: u8 = 5;
+pub const DISPUTE_BOND_LAMPORTS: u64 = 10_000_000; // 0.01 SOL
+pub const DISPUTE_BOND_BPS: u64 = 500; // plus 5% of a lamport bounty
+pub const MIN_ARBITRATOR_STAKE_LAMPORTS: u64 = 1_000_000_000; // 1 SOL
+pub const ARBITRATOR_FEE_BPS: u64 = 2_000; // 20% of the filing bond
+pub const ARBITRATOR_SLASH_BPS: u64 = 1_000; // 10% of arbitrator stake
+pub const SLASH_BOUNTY_BPS: u64 = 1_000; // 10% of the bounty
+pub const SLASH_STAKE_BPS: u64 = 500; // 5% of the agent's free stake
+pub const SLASH_REPEAT_LOSS_BPS: u64 = 5_000; // +50% per prior dispute loss
+pub const SLASH_MAX_MULTIPLIER_BPS: u64 = 30_000; // at most 3x
+pub const MAX_SLASH_LAMPORTS: u64 = 10_000_000_000; // 10 SOL
+pub const SLASH_CLIENT_BPS: u64 = 7_000; // 70% to the wronged client
+pub const SLASH_TREASURY_BPS: u64 = 2_000; // 20% to the treasury, 10% to insurance
+pub const CLIENT_LOSS_FEE_BPS: u64 = 100; // +1% fee per dispute a client has lost
+pub const MAX_CLIENT_FEE_BPS: u64 = 1_000; // client fees capped at 10%
 pub const MAX_AGENT_NAME_LEN: usize = 32;
 pub const MAX_TASK_TITLE_LEN: usize = 64;
+
+// Account space caps for the arbitrator registry, dispute panels and dispute queue,
+// and the voting rounds a panel gets before the fallback ruling applies
+pub const MAX_ARBITRATORS: usize = 64;
+pub const MAX_PANEL_SIZE: usize = 9;
+pub const MAX_QUEUED_DISPUTES: usize = 128; // older entries are overwritten
+pub const MAX_VOTING_ROUNDS: u8 = 3;
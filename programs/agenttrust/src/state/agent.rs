@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
-use crate::{MAX_AGENT_NAME_LEN, MINIMUM_STAKE_LAMPORTS};
+use crate::MAX_AGENT_NAME_LEN;
 use crate::errors::AgentTrustError;
+use crate::state::{AgentSlash, ProtocolConfig, BPS_DENOMINATOR};
 
 #[account]
 pub struct Agent {
@@ -19,12 +20,30 @@ pub struct Agent {
     /// Total number of tasks attempted
     pub total_tasks: u64,
     
-    /// Total stake in lamports
+    /// Total stake in lamports (held in the agent's stake vault)
     pub total_stake: u64,
     
     /// Number of disputes lost
     pub dispute_losses: u64,
     
+    /// Success credit from split dispute rulings (basis points of a task)
+    pub split_success_bps: u64,
+    
+    /// Loss weight from split dispute rulings (basis points of a dispute loss)
+    pub split_loss_bps: u64,
+    
+    /// Stake locked as collateral in claimed tasks' escrows
+    pub locked_collateral: u64,
+    
+    /// Tasks currently claimed, submitted or disputed
+    pub active_tasks: u64,
+    
+    /// Stake requested for withdrawal (still slashable, excluded from reputation)
+    pub pending_unstake: u64,
+    
+    /// Unix timestamp when the pending unstake can be withdrawn
+    pub unstake_available_at: i64,
+    
     /// Unix timestamp when agent registered
     pub created_at: i64,
     
@@ -34,8 +53,9 @@ pub struct Agent {
 
 impl Agent {
     /// Calculate space needed for Agent account
-    /// 8 (discriminator) + 32 (owner) + 4 + 32 (name) + 8*6 (u64 fields) + 8 (created_at) + 1 (bump)
-    pub const SPACE: usize = 8 + 32 + 4 + MAX_AGENT_NAME_LEN + 48 + 8 + 1;
+    /// 8 (discriminator) + 32 (owner) + 4 + 32 (name) + 8*10 (u64 fields) + 8 (unstake_available_at) +
+    /// 8 (created_at) + 1 (bump)
+    pub const SPACE: usize = 8 + 32 + 4 + MAX_AGENT_NAME_LEN + 80 + 8 + 8 + 1;
     
     /// Register a new agent
     pub fn register(
@@ -43,15 +63,16 @@ impl Agent {
         owner: Pubkey,
         name: String,
         initial_stake: u64,
+        config: &ProtocolConfig,
         bump: u8,
     ) -> Result<()> {
         require!(
-            name.len() <= MAX_AGENT_NAME_LEN,
+            name.len() <= config.max_agent_name_len as usize,
             AgentTrustError::NameTooLong
         );
         
         require!(
-            initial_stake >= MINIMUM_STAKE_LAMPORTS,
+            initial_stake >= config.min_stake_lamports,
             AgentTrustError::InsufficientStake
         );
         
@@ -62,6 +83,12 @@ impl Agent {
         self.total_tasks = 0;
         self.total_stake = initial_stake;
         self.dispute_losses = 0;
+        self.split_success_bps = 0;
+        self.split_loss_bps = 0;
+        self.locked_collateral = 0;
+        self.active_tasks = 0;
+        self.pending_unstake = 0;
+        self.unstake_available_at = 0;
         self.created_at = Clock::get()?.unix_timestamp;
         self.bump = bump;
         
@@ -69,8 +96,12 @@ impl Agent {
     }
     
     /// Increase stake amount
-    pub fn increase_stake(&mut self, amount: u64) {
-        self.total_stake += amount;
+    pub fn increase_stake(&mut self, amount: u64) -> Result<()> {
+        self.total_stake = self
+            .total_stake
+            .checked_add(amount)
+            .ok_or(AgentTrustError::Overflow)?;
+        Ok(())
     }
     
     /// Decrease stake (for slashing)
@@ -80,9 +111,107 @@ impl Agent {
             AgentTrustError::InsufficientStake
         );
         self.total_stake -= amount;
+        
+        // Slashing can eat into stake that is already unbonding
+        self.pending_unstake = self.pending_unstake.min(self.total_stake);
         Ok(())
     }
     
+    /// Stake that still counts towards reputation, including collateral locked in tasks
+    pub fn bonded_stake(&self) -> u64 {
+        (self.total_stake + self.locked_collateral).saturating_sub(self.pending_unstake)
+    }
+    
+    /// Move stake into a task's collateral lock; stake that is unbonding can't be locked
+    pub fn lock_collateral(&mut self, amount: u64) -> Result<()> {
+        require!(
+            self.total_stake.saturating_sub(self.pending_unstake) >= amount,
+            AgentTrustError::InsufficientCollateral
+        );
+        
+        self.total_stake -= amount;
+        self.locked_collateral = self
+            .locked_collateral
+            .checked_add(amount)
+            .ok_or(AgentTrustError::Overflow)?;
+        Ok(())
+    }
+    
+    /// Return a task's collateral to stake
+    pub fn unlock_collateral(&mut self, amount: u64) -> Result<()> {
+        self.forfeit_collateral(amount)?;
+        self.increase_stake(amount)
+    }
+    
+    /// Give up a task's collateral (slashed to the client)
+    pub fn forfeit_collateral(&mut self, amount: u64) -> Result<()> {
+        self.locked_collateral = self
+            .locked_collateral
+            .checked_sub(amount)
+            .ok_or(AgentTrustError::InsufficientStake)?;
+        Ok(())
+    }
+    
+    /// Give up the collateral and free stake taken by a slash
+    pub fn take_slash(&mut self, slash: &AgentSlash) -> Result<()> {
+        self.forfeit_collateral(slash.collateral)?;
+        self.decrease_stake(slash.stake)
+    }
+    
+    /// Start unbonding stake; adding to a pending request restarts the timer
+    pub fn request_unstake(&mut self, amount: u64, available_at: i64) -> Result<()> {
+        require!(
+            self.active_tasks == 0,
+            AgentTrustError::ActiveTasksOutstanding
+        );
+        
+        require!(
+            amount > 0 && amount <= self.bonded_stake(),
+            AgentTrustError::InsufficientStake
+        );
+        
+        self.pending_unstake = self
+            .pending_unstake
+            .checked_add(amount)
+            .ok_or(AgentTrustError::Overflow)?;
+        self.unstake_available_at = available_at;
+        Ok(())
+    }
+    
+    /// Finish unbonding, returning the amount to pay out
+    pub fn complete_unstake(&mut self) -> Result<u64> {
+        require!(
+            self.active_tasks == 0,
+            AgentTrustError::ActiveTasksOutstanding
+        );
+        
+        require!(
+            self.pending_unstake > 0,
+            AgentTrustError::NoPendingUnstake
+        );
+        
+        require!(
+            Clock::get()?.unix_timestamp >= self.unstake_available_at,
+            AgentTrustError::UnbondingNotComplete
+        );
+        
+        let amount = self.pending_unstake;
+        self.total_stake -= amount;
+        self.pending_unstake = 0;
+        self.unstake_available_at = 0;
+        Ok(amount)
+    }
+    
+    /// Track a newly claimed task
+    pub fn begin_task(&mut self) {
+        self.active_tasks += 1;
+    }
+    
+    /// Track a task leaving the claimed/submitted/disputed states
+    pub fn finish_task(&mut self) {
+        self.active_tasks = self.active_tasks.saturating_sub(1);
+    }
+    
     /// Record successful task completion
     pub fn record_success(&mut self) {
         self.successful_tasks += 1;
@@ -99,25 +228,31 @@ impl Agent {
         self.dispute_losses += 1;
     }
     
+    /// Record a split ruling: partial success and partial loss in proportion to the agent's share
+    pub fn record_split(&mut self, agent_bps: u64) {
+        self.total_tasks += 1;
+        self.split_success_bps += agent_bps;
+        self.split_loss_bps += BPS_DENOMINATOR - agent_bps;
+    }
+    
     /// Calculate reputation score
     /// Formula: (Success% * 0.6) + (NormalizedStake * 0.4) - (DisputeLosses * 10)
     pub fn calculate_reputation(&self) -> u64 {
-        // Success rate component (60% weight)
-        let success_rate = if self.total_tasks > 0 {
-            (self.successful_tasks * 100) / self.total_tasks
-        } else {
-            0
-        };
+        // Success rate component (60% weight); split rulings count fractionally
+        let success_credit = self.successful_tasks * BPS_DENOMINATOR + self.split_success_bps;
+        let success_rate = (success_credit * 100)
+            .checked_div(self.total_tasks * BPS_DENOMINATOR)
+            .unwrap_or(0);
         let success_component = (success_rate * 60) / 100;
         
         // Stake component (40% weight) - normalized to 0-100
         // Max expected stake: 100 SOL = 100,000,000,000 lamports
         let max_stake = 100_000_000_000u64;
-        let stake_normalized = ((self.total_stake.min(max_stake)) * 100) / max_stake;
+        let stake_normalized = ((self.bonded_stake().min(max_stake)) * 100) / max_stake;
         let stake_component = (stake_normalized * 40) / 100;
         
-        // Dispute penalty: -10 points per loss
-        let dispute_penalty = self.dispute_losses * 10;
+        // Dispute penalty: -10 points per loss, pro rata for split rulings
+        let dispute_penalty = self.dispute_losses * 10 + (self.split_loss_bps * 10) / BPS_DENOMINATOR;
         
         // Calculate final score with floor at 0
         let raw_score = success_component + stake_component;
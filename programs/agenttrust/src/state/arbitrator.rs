@@ -0,0 +1,350 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::{ArbitratorStatus, MAX_ARBITRATORS};
+use crate::errors::AgentTrustError;
+use crate::state::{ProtocolConfig, StakeVault};
+use crate::utils::{apply_bps, move_lamports};
+
+/// An arbitrator authorized by the protocol admin to resolve disputes
+#[account]
+pub struct Arbitrator {
+    /// Wallet that signs dispute resolutions
+    pub authority: Pubkey,
+    
+    /// Whether the arbitrator may currently resolve disputes
+    pub status: ArbitratorStatus,
+    
+    /// Total stake in lamports (held in the arbitrator's stake vault)
+    pub total_stake: u64,
+    
+    /// Number of disputes this arbitrator has revealed a vote in
+    pub votes_cast: u64,
+    
+    /// Revealed votes that matched the dispute's final ruling
+    pub votes_with_majority: u64,
+    
+    /// Votes for a ruling that was later overturned on appeal
+    pub overturned_votes: u64,
+    
+    /// Commit or reveal deadlines this arbitrator missed
+    pub missed_deadlines: u64,
+    
+    /// Sum of seconds from voting opening to each reveal (average = total / votes_cast)
+    pub total_vote_latency: u64,
+    
+    /// Lamports earned in arbitration fees (credited to stake)
+    pub rewards_earned: u64,
+    
+    /// Unix timestamp when the arbitrator was registered
+    pub added_at: i64,
+    
+    /// Unix timestamp of the latest suspension (0 if never suspended)
+    pub suspended_at: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Arbitrator {
+    /// Calculate space needed for Arbitrator account
+    /// 8 (discriminator) + 32 (authority) + 1 (status) + 8*7 (u64 fields) + 8 (added_at) +
+    /// 8 (suspended_at) + 1 (bump)
+    pub const SPACE: usize = 8 + 32 + 1 + 56 + 8 + 8 + 1;
+    
+    /// Register a new active arbitrator
+    pub fn register(&mut self, authority: Pubkey, bump: u8) -> Result<()> {
+        self.authority = authority;
+        self.status = ArbitratorStatus::Active;
+        self.total_stake = 0;
+        self.votes_cast = 0;
+        self.votes_with_majority = 0;
+        self.overturned_votes = 0;
+        self.missed_deadlines = 0;
+        self.total_vote_latency = 0;
+        self.rewards_earned = 0;
+        self.added_at = Clock::get()?.unix_timestamp;
+        self.suspended_at = 0;
+        self.bump = bump;
+        
+        Ok(())
+    }
+    
+    /// Stop the arbitrator from resolving disputes
+    pub fn suspend(&mut self) -> Result<()> {
+        require!(
+            self.status == ArbitratorStatus::Active,
+            AgentTrustError::ArbitratorNotActive
+        );
+        
+        self.status = ArbitratorStatus::Suspended;
+        self.suspended_at = Clock::get()?.unix_timestamp;
+        
+        Ok(())
+    }
+    
+    /// Let a suspended arbitrator resolve disputes again. It rejoins the panel pool
+    /// once its stake is back at the minimum.
+    pub fn reinstate(&mut self) -> Result<()> {
+        require!(
+            self.status == ArbitratorStatus::Suspended,
+            AgentTrustError::ArbitratorNotSuspended
+        );
+        
+        self.status = ArbitratorStatus::Active;
+        
+        Ok(())
+    }
+    
+    /// Check stake can be withdrawn: an active arbitrator must keep the minimum,
+    /// a suspended one can leave entirely once the unbonding period has passed
+    pub fn check_withdrawal(&self, amount: u64, config: &ProtocolConfig) -> Result<()> {
+        require!(
+            amount <= self.total_stake,
+            AgentTrustError::InsufficientStake
+        );
+        
+        if self.is_active() {
+            require!(
+                self.total_stake - amount >= config.min_arbitrator_stake_lamports,
+                AgentTrustError::ArbitratorStakeLocked
+            );
+        } else {
+            let unlocked_at = self
+                .suspended_at
+                .checked_add(config.unbonding_period_seconds)
+                .ok_or(AgentTrustError::Overflow)?;
+            require!(
+                Clock::get()?.unix_timestamp >= unlocked_at,
+                AgentTrustError::ArbitratorStakeLocked
+            );
+        }
+        
+        Ok(())
+    }
+    
+    /// Check the arbitrator can be removed with its stake: it must be suspended and past
+    /// the unbonding period, so recent rulings can still be slashed until then
+    pub fn check_removal(&self, config: &ProtocolConfig) -> Result<()> {
+        require!(
+            !self.is_active(),
+            AgentTrustError::ArbitratorStillActive
+        );
+        
+        self.check_withdrawal(self.total_stake, config)
+    }
+    
+    /// Record a revealed vote and how long after voting opened it came in.
+    /// Re-run rounds vote on the same dispute again, so only the first round counts.
+    pub fn record_vote(&mut self, round: u8, latency_seconds: i64) -> Result<()> {
+        if round > 0 {
+            return Ok(());
+        }
+        
+        self.votes_cast = self
+            .votes_cast
+            .checked_add(1)
+            .ok_or(AgentTrustError::Overflow)?;
+        self.total_vote_latency = self
+            .total_vote_latency
+            .saturating_add(latency_seconds.max(0) as u64);
+        
+        Ok(())
+    }
+    
+    /// Penalize a missed commit or reveal deadline: count it and suspend the arbitrator
+    pub fn record_missed_deadline(&mut self) -> Result<()> {
+        self.missed_deadlines = self
+            .missed_deadlines
+            .checked_add(1)
+            .ok_or(AgentTrustError::Overflow)?;
+        
+        if self.is_active() {
+            self.suspend()?;
+        }
+        
+        Ok(())
+    }
+    
+    /// Check if the arbitrator may resolve disputes
+    pub fn is_active(&self) -> bool {
+        self.status == ArbitratorStatus::Active
+    }
+    
+    /// Check if the arbitrator can be seated on panels
+    pub fn is_eligible(&self, config: &ProtocolConfig) -> bool {
+        self.is_active() && self.total_stake >= config.min_arbitrator_stake_lamports
+    }
+}
+
+/// An arbitrator and its stake vault, loaded from an instruction's remaining accounts
+pub struct StakedArbitrator<'info> {
+    pub arbitrator: Account<'info, Arbitrator>,
+    pub vault: Account<'info, StakeVault>,
+}
+
+impl<'info> StakedArbitrator<'info> {
+    /// Find an arbitrator's PDA and stake vault among `accounts`.
+    /// Returns None when the arbitrator has been removed (its account closed).
+    pub fn load(
+        accounts: &'info [AccountInfo<'info>],
+        authority: &Pubkey,
+    ) -> Result<Option<Self>> {
+        let (arbitrator_address, _) = Pubkey::find_program_address(
+            &[b"arbitrator", authority.as_ref()],
+            &crate::ID,
+        );
+        let (vault_address, _) = Pubkey::find_program_address(
+            &[b"stake_vault", arbitrator_address.as_ref()],
+            &crate::ID,
+        );
+        let find = |address: Pubkey| {
+            accounts
+                .iter()
+                .find(|info| info.key() == address)
+                .ok_or_else(|| error!(AgentTrustError::MissingArbitratorAccount))
+        };
+        
+        let arbitrator_info = find(arbitrator_address)?;
+        if arbitrator_info.data_is_empty() {
+            return Ok(None);
+        }
+        
+        Ok(Some(Self {
+            arbitrator: Account::try_from(arbitrator_info)?,
+            vault: Account::try_from(find(vault_address)?)?,
+        }))
+    }
+    
+    /// Pay an arbitration fee from a program-owned account into the arbitrator's stake
+    pub fn reward(&mut self, from: &AccountInfo<'info>, amount: u64) -> Result<()> {
+        move_lamports(from, &self.vault.to_account_info(), amount)?;
+        
+        let arbitrator = &mut self.arbitrator;
+        arbitrator.total_stake = arbitrator
+            .total_stake
+            .checked_add(amount)
+            .ok_or(AgentTrustError::Overflow)?;
+        arbitrator.rewards_earned = arbitrator
+            .rewards_earned
+            .checked_add(amount)
+            .ok_or(AgentTrustError::Overflow)?;
+        
+        Ok(())
+    }
+    
+    /// Slash the configured share of the arbitrator's stake into `to`, returning the amount
+    pub fn slash(&mut self, to: &AccountInfo<'info>, config: &ProtocolConfig) -> Result<u64> {
+        let amount = apply_bps(self.arbitrator.total_stake, config.arbitrator_slash_bps);
+        
+        StakeVault::withdraw(&self.vault, to, amount)?;
+        self.arbitrator.total_stake -= amount;
+        
+        Ok(amount)
+    }
+    
+    /// Persist changes to the arbitrator account
+    pub fn exit(&self) -> Result<()> {
+        self.arbitrator.exit(&crate::ID)
+    }
+}
+
+/// Active, sufficiently staked arbitrators that dispute panels are drawn from
+#[account]
+pub struct ArbitratorRegistry {
+    /// Authorities of active arbitrators
+    pub arbitrators: Vec<Pubkey>,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ArbitratorRegistry {
+    /// Calculate space needed for ArbitratorRegistry account
+    /// 8 (discriminator) + 4 + 32 * MAX_ARBITRATORS (arbitrators) + 1 (bump)
+    pub const SPACE: usize = 8 + 4 + 32 * MAX_ARBITRATORS + 1;
+    
+    /// Add an arbitrator to the active set
+    pub fn add(&mut self, authority: Pubkey) -> Result<()> {
+        if self.arbitrators.contains(&authority) {
+            return Ok(());
+        }
+        
+        require!(
+            self.arbitrators.len() < MAX_ARBITRATORS,
+            AgentTrustError::ArbitratorRegistryFull
+        );
+        
+        self.arbitrators.push(authority);
+        
+        Ok(())
+    }
+    
+    /// Keep an arbitrator in the active set only while it is active and sufficiently staked
+    pub fn sync(&mut self, arbitrator: &Arbitrator, config: &ProtocolConfig) -> Result<()> {
+        if arbitrator.is_eligible(config) {
+            self.add(arbitrator.authority)
+        } else {
+            self.remove(&arbitrator.authority);
+            Ok(())
+        }
+    }
+    
+    /// Drop an arbitrator from the active set
+    pub fn remove(&mut self, authority: &Pubkey) {
+        self.arbitrators.retain(|member| member != authority);
+    }
+    
+    /// Active arbitrators other than `excluded`
+    fn candidates(&self, excluded: &[Pubkey]) -> Vec<Pubkey> {
+        self.arbitrators
+            .iter()
+            .filter(|member| !excluded.contains(member))
+            .copied()
+            .collect()
+    }
+    
+    /// Check a panel of `size` could be drawn without `excluded`, so a dispute isn't
+    /// opened or appealed with too few arbitrators to hear it
+    pub fn check_can_seat(&self, size: usize, excluded: &[Pubkey]) -> Result<()> {
+        require!(
+            self.candidates(excluded).len() >= size,
+            AgentTrustError::NotEnoughArbitrators
+        );
+        
+        Ok(())
+    }
+    
+    /// Draw `size` distinct arbitrators, skipping `excluded` (the dispute's parties).
+    /// Selection is a seeded shuffle, so callers should mix in values the filer can't choose.
+    pub fn select_panel(
+        &self,
+        size: usize,
+        seed: &[u8],
+        excluded: &[Pubkey],
+    ) -> Result<Vec<Pubkey>> {
+        self.check_can_seat(size, excluded)?;
+        let mut candidates = self.candidates(excluded);
+        
+        let mut entropy = hashv(&[seed]).to_bytes();
+        let mut panel = Vec::with_capacity(size);
+        for _ in 0..size {
+            let mut draw = [0u8; 8];
+            draw.copy_from_slice(&entropy[..8]);
+            let index = (u64::from_le_bytes(draw) % candidates.len() as u64) as usize;
+            panel.push(candidates.swap_remove(index));
+            entropy = hashv(&[&entropy]).to_bytes();
+        }
+        
+        Ok(panel)
+    }
+}
+
+/// Seeds for Arbitrator PDA: [b"arbitrator", authority_pubkey]
+pub fn get_arbitrator_seeds(authority: &Pubkey) -> Vec<&[u8]> {
+    vec![b"arbitrator", authority.as_ref()]
+}
+
+/// Seeds for ArbitratorRegistry PDA: [b"arbitrator_registry"]
+pub fn get_arbitrator_registry_seeds() -> Vec<&'static [u8]> {
+    vec![b"arbitrator_registry"]
+}
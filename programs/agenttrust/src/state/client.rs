@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::errors::AgentTrustError;
+
+#[account]
+pub struct ClientProfile {
+    /// Client's wallet address (owner)
+    pub client: Pubkey,
+    
+    /// Number of tasks created so far (next task id)
+    pub task_count: u64,
+    
+    /// Disputes ruled against this client (each one raises the client's protocol fee)
+    pub disputes_lost: u64,
+    
+    /// Unix timestamp when the profile was created
+    pub created_at: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ClientProfile {
+    /// Calculate space needed for ClientProfile account
+    /// 8 (discriminator) + 32 (client) + 8 (task_count) + 8 (disputes_lost) + 8 (created_at) + 1 (bump)
+    pub const SPACE: usize = 8 + 32 + 8 + 8 + 8 + 1;
+    
+    /// Initialize the profile on first use
+    pub fn init_if_new(&mut self, client: Pubkey, bump: u8) -> Result<()> {
+        if self.client == Pubkey::default() {
+            self.client = client;
+            self.task_count = 0;
+            self.disputes_lost = 0;
+            self.created_at = Clock::get()?.unix_timestamp;
+            self.bump = bump;
+        }
+        
+        Ok(())
+    }
+    
+    /// Record a dispute ruled against the client
+    pub fn record_dispute_loss(&mut self) {
+        self.disputes_lost += 1;
+    }
+    
+    /// Take the next task id and advance the counter
+    pub fn next_task_id(&mut self) -> Result<u64> {
+        let task_id = self.task_count;
+        self.task_count = self
+            .task_count
+            .checked_add(1)
+            .ok_or(AgentTrustError::Overflow)?;
+        Ok(task_id)
+    }
+}
+
+/// Seeds for ClientProfile PDA: [b"client", client_pubkey]
+pub fn get_client_seeds(client: &Pubkey) -> Vec<&[u8]> {
+    vec![b"client", client.as_ref()]
+}
@@ -0,0 +1,545 @@
+use anchor_lang::prelude::*;
+use crate::{
+    TRANSACTION_FEE_BPS, MINIMUM_STAKE_LAMPORTS, DISPUTE_WINDOW_SECONDS,
+    UNBONDING_PERIOD_SECONDS, EXPIRY_SLASH_BPS, REVIEW_WINDOW_SECONDS,
+    PANEL_SIZE, MAX_PANEL_SIZE, COMMIT_WINDOW_SECONDS, REVEAL_WINDOW_SECONDS,
+    APPEAL_WINDOW_SECONDS, APPEAL_BOND_LAMPORTS, APPEAL_PANEL_SIZE,
+    DISPUTE_BOND_LAMPORTS, DISPUTE_BOND_BPS, MIN_ARBITRATOR_STAKE_LAMPORTS,
+    ARBITRATOR_FEE_BPS, ARBITRATOR_SLASH_BPS, SLASH_BOUNTY_BPS, SLASH_STAKE_BPS,
+    SLASH_REPEAT_LOSS_BPS, SLASH_MAX_MULTIPLIER_BPS, MAX_SLASH_LAMPORTS,
+    SLASH_CLIENT_BPS, SLASH_TREASURY_BPS, CLIENT_LOSS_FEE_BPS,
+    MAX_CLIENT_FEE_BPS, SEATING_WINDOW_SECONDS, MAX_AGENT_NAME_LEN,
+    MAX_TASK_TITLE_LEN,
+};
+use crate::errors::AgentTrustError;
+use crate::state::{Agent, Task};
+use crate::utils::apply_bps;
+
+/// Basis point denominator (100% = 10,000 bps)
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+#[account]
+pub struct ProtocolConfig {
+    /// Authority allowed to update protocol parameters
+    pub admin: Pubkey,
+    
+    /// Wallet that treasury fee withdrawals are paid to
+    pub treasury: Pubkey,
+    
+    /// Protocol fee charged on top of each bounty (basis points)
+    pub fee_bps: u64,
+    
+    /// Minimum stake required to register an agent (lamports)
+    pub min_stake_lamports: u64,
+    
+    /// Length of the dispute evidence period (seconds)
+    pub dispute_window_seconds: i64,
+    
+    /// Delay between requesting an unstake and withdrawing it (seconds)
+    pub unbonding_period_seconds: i64,
+    
+    /// Share of an agent's stake slashed when a claimed task expires (basis points)
+    pub expiry_slash_bps: u64,
+    
+    /// Time a client has to confirm or dispute a submission before anyone can auto-confirm (seconds)
+    pub review_window_seconds: i64,
+    
+    /// Number of arbitrators assigned to each dispute (odd, capped by MAX_PANEL_SIZE)
+    pub panel_size: u8,
+    
+    /// Time panel arbitrators have to commit sealed votes once voting opens (seconds)
+    pub commit_window_seconds: i64,
+    
+    /// Time panel arbitrators have to reveal their votes after the commit window (seconds)
+    pub reveal_window_seconds: i64,
+    
+    /// Time the losing party has to appeal a ruling before funds are released (seconds)
+    pub appeal_window_seconds: i64,
+    
+    /// Bond posted with an appeal, forfeited if the ruling stands (lamports)
+    pub appeal_bond_lamports: u64,
+    
+    /// Number of arbitrators seated to hear an appeal (odd, capped by MAX_PANEL_SIZE)
+    pub appeal_panel_size: u8,
+    
+    /// Fixed bond posted when filing a dispute (lamports)
+    pub dispute_bond_lamports: u64,
+    
+    /// Additional filing bond as a share of the bounty, for lamport tasks (basis points)
+    pub dispute_bond_bps: u64,
+    
+    /// Stake an arbitrator must hold to be seated on panels and vote (lamports)
+    pub min_arbitrator_stake_lamports: u64,
+    
+    /// Share of the filing bond paid to panel arbitrators who voted with the ruling (basis points)
+    pub arbitrator_fee_bps: u64,
+    
+    /// Share of an arbitrator's stake slashed for a missed deadline or an overturned vote (basis points)
+    pub arbitrator_slash_bps: u64,
+    
+    /// Share of a lamport bounty slashed from an agent that loses a dispute (basis points)
+    pub slash_bounty_bps: u64,
+    
+    /// Share of the losing agent's free stake slashed on top of the bounty share (basis points)
+    pub slash_stake_bps: u64,
+    
+    /// Increase of the slash multiplier for each dispute the agent already lost (basis points)
+    pub slash_repeat_loss_bps: u64,
+    
+    /// Cap on the escalated slash multiplier (basis points, at least 100%)
+    pub slash_max_multiplier_bps: u64,
+    
+    /// Cap on a single agent slash, forfeited collateral included (lamports)
+    pub max_slash_lamports: u64,
+    
+    /// Share of a slash paid to the wronged client (basis points)
+    pub slash_client_bps: u64,
+    
+    /// Share of a slash paid to the treasury; the rest funds the insurance pool (basis points)
+    pub slash_treasury_bps: u64,
+    
+    /// Fee surcharge per dispute the client has lost (basis points of the bounty)
+    pub client_loss_fee_bps: u64,
+    
+    /// Cap on a client's fee including dispute-loss surcharges (basis points)
+    pub max_client_fee_bps: u64,
+    
+    /// Time to seat a panel after the evidence period or an appeal before the dispute settles without one (seconds)
+    pub seating_window_seconds: i64,
+    
+    /// Maximum agent name length (capped by MAX_AGENT_NAME_LEN)
+    pub max_agent_name_len: u32,
+    
+    /// Maximum task title length (capped by MAX_TASK_TITLE_LEN)
+    pub max_task_title_len: u32,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// Slashed lamports divided per the configured splits
+pub struct SlashSplit {
+    pub client: u64,
+    pub treasury: u64,
+    pub insurance: u64,
+}
+
+/// Lamports taken from an agent by a slash: the task's collateral goes first,
+/// then free stake
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AgentSlash {
+    pub collateral: u64,
+    pub stake: u64,
+}
+
+impl AgentSlash {
+    pub fn total(&self) -> u64 {
+        self.collateral + self.stake
+    }
+}
+
+/// Parameters accepted by `update_config`; `None` leaves a value unchanged
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ConfigUpdate {
+    pub admin: Option<Pubkey>,
+    pub treasury: Option<Pubkey>,
+    pub fee_bps: Option<u64>,
+    pub min_stake_lamports: Option<u64>,
+    pub dispute_window_seconds: Option<i64>,
+    pub unbonding_period_seconds: Option<i64>,
+    pub expiry_slash_bps: Option<u64>,
+    pub review_window_seconds: Option<i64>,
+    pub panel_size: Option<u8>,
+    pub commit_window_seconds: Option<i64>,
+    pub reveal_window_seconds: Option<i64>,
+    pub appeal_window_seconds: Option<i64>,
+    pub appeal_bond_lamports: Option<u64>,
+    pub appeal_panel_size: Option<u8>,
+    pub dispute_bond_lamports: Option<u64>,
+    pub dispute_bond_bps: Option<u64>,
+    pub min_arbitrator_stake_lamports: Option<u64>,
+    pub arbitrator_fee_bps: Option<u64>,
+    pub arbitrator_slash_bps: Option<u64>,
+    pub slash_bounty_bps: Option<u64>,
+    pub slash_stake_bps: Option<u64>,
+    pub slash_repeat_loss_bps: Option<u64>,
+    pub slash_max_multiplier_bps: Option<u64>,
+    pub max_slash_lamports: Option<u64>,
+    pub slash_client_bps: Option<u64>,
+    pub slash_treasury_bps: Option<u64>,
+    pub client_loss_fee_bps: Option<u64>,
+    pub max_client_fee_bps: Option<u64>,
+    pub seating_window_seconds: Option<i64>,
+    pub max_agent_name_len: Option<u32>,
+    pub max_task_title_len: Option<u32>,
+}
+
+impl ProtocolConfig {
+    /// Calculate space needed for ProtocolConfig account
+    /// 8 (discriminator) + 32 (admin) + 32 (treasury) + 8 (fee_bps) + 8 (min stake) +
+    /// 8 (dispute window) + 8 (unbonding period) + 8 (expiry slash) + 8 (review window) +
+    /// 1 (panel size) + 8 (commit window) + 8 (reveal window) + 8 (appeal window) +
+    /// 8 (appeal bond) + 1 (appeal panel size) + 8 (dispute bond) + 8 (dispute bond bps) +
+    /// 8 (min arbitrator stake) + 8 (arbitrator fee) + 8 (arbitrator slash) + 8 (slash bounty) +
+    /// 8 (slash stake) + 8 (repeat loss) + 8 (max multiplier) + 8 (max slash) +
+    /// 8 (client share) + 8 (treasury share) + 8 (client loss fee) + 8 (max client fee) +
+    /// 8 (seating window) + 4 (max name len) + 4 (max title len) + 1 (bump)
+    pub const SPACE: usize = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 1 + 8 + 8 + 8 + 8 + 1 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 4 + 4 + 1;
+    
+    /// Initialize config with the compile-time defaults
+    pub fn initialize(&mut self, admin: Pubkey, treasury: Pubkey, bump: u8) {
+        self.admin = admin;
+        self.treasury = treasury;
+        self.fee_bps = TRANSACTION_FEE_BPS;
+        self.min_stake_lamports = MINIMUM_STAKE_LAMPORTS;
+        self.dispute_window_seconds = DISPUTE_WINDOW_SECONDS;
+        self.unbonding_period_seconds = UNBONDING_PERIOD_SECONDS;
+        self.expiry_slash_bps = EXPIRY_SLASH_BPS;
+        self.review_window_seconds = REVIEW_WINDOW_SECONDS;
+        self.panel_size = PANEL_SIZE;
+        self.commit_window_seconds = COMMIT_WINDOW_SECONDS;
+        self.reveal_window_seconds = REVEAL_WINDOW_SECONDS;
+        self.appeal_window_seconds = APPEAL_WINDOW_SECONDS;
+        self.appeal_bond_lamports = APPEAL_BOND_LAMPORTS;
+        self.appeal_panel_size = APPEAL_PANEL_SIZE;
+        self.dispute_bond_lamports = DISPUTE_BOND_LAMPORTS;
+        self.dispute_bond_bps = DISPUTE_BOND_BPS;
+        self.min_arbitrator_stake_lamports = MIN_ARBITRATOR_STAKE_LAMPORTS;
+        self.arbitrator_fee_bps = ARBITRATOR_FEE_BPS;
+        self.arbitrator_slash_bps = ARBITRATOR_SLASH_BPS;
+        self.slash_bounty_bps = SLASH_BOUNTY_BPS;
+        self.slash_stake_bps = SLASH_STAKE_BPS;
+        self.slash_repeat_loss_bps = SLASH_REPEAT_LOSS_BPS;
+        self.slash_max_multiplier_bps = SLASH_MAX_MULTIPLIER_BPS;
+        self.max_slash_lamports = MAX_SLASH_LAMPORTS;
+        self.slash_client_bps = SLASH_CLIENT_BPS;
+        self.slash_treasury_bps = SLASH_TREASURY_BPS;
+        self.client_loss_fee_bps = CLIENT_LOSS_FEE_BPS;
+        self.max_client_fee_bps = MAX_CLIENT_FEE_BPS;
+        self.seating_window_seconds = SEATING_WINDOW_SECONDS;
+        self.max_agent_name_len = MAX_AGENT_NAME_LEN as u32;
+        self.max_task_title_len = MAX_TASK_TITLE_LEN as u32;
+        self.bump = bump;
+    }
+    
+    /// Apply a partial update and validate the result
+    pub fn update(&mut self, update: ConfigUpdate) -> Result<()> {
+        if let Some(admin) = update.admin {
+            self.admin = admin;
+        }
+        if let Some(treasury) = update.treasury {
+            self.treasury = treasury;
+        }
+        if let Some(fee_bps) = update.fee_bps {
+            self.fee_bps = fee_bps;
+        }
+        if let Some(min_stake_lamports) = update.min_stake_lamports {
+            self.min_stake_lamports = min_stake_lamports;
+        }
+        if let Some(dispute_window_seconds) = update.dispute_window_seconds {
+            self.dispute_window_seconds = dispute_window_seconds;
+        }
+        if let Some(unbonding_period_seconds) = update.unbonding_period_seconds {
+            self.unbonding_period_seconds = unbonding_period_seconds;
+        }
+        if let Some(expiry_slash_bps) = update.expiry_slash_bps {
+            self.expiry_slash_bps = expiry_slash_bps;
+        }
+        if let Some(review_window_seconds) = update.review_window_seconds {
+            self.review_window_seconds = review_window_seconds;
+        }
+        if let Some(panel_size) = update.panel_size {
+            self.panel_size = panel_size;
+        }
+        if let Some(commit_window_seconds) = update.commit_window_seconds {
+            self.commit_window_seconds = commit_window_seconds;
+        }
+        if let Some(reveal_window_seconds) = update.reveal_window_seconds {
+            self.reveal_window_seconds = reveal_window_seconds;
+        }
+        if let Some(appeal_window_seconds) = update.appeal_window_seconds {
+            self.appeal_window_seconds = appeal_window_seconds;
+        }
+        if let Some(appeal_bond_lamports) = update.appeal_bond_lamports {
+            self.appeal_bond_lamports = appeal_bond_lamports;
+        }
+        if let Some(appeal_panel_size) = update.appeal_panel_size {
+            self.appeal_panel_size = appeal_panel_size;
+        }
+        if let Some(dispute_bond_lamports) = update.dispute_bond_lamports {
+            self.dispute_bond_lamports = dispute_bond_lamports;
+        }
+        if let Some(dispute_bond_bps) = update.dispute_bond_bps {
+            self.dispute_bond_bps = dispute_bond_bps;
+        }
+        if let Some(min_arbitrator_stake_lamports) = update.min_arbitrator_stake_lamports {
+            self.min_arbitrator_stake_lamports = min_arbitrator_stake_lamports;
+        }
+        if let Some(arbitrator_fee_bps) = update.arbitrator_fee_bps {
+            self.arbitrator_fee_bps = arbitrator_fee_bps;
+        }
+        if let Some(arbitrator_slash_bps) = update.arbitrator_slash_bps {
+            self.arbitrator_slash_bps = arbitrator_slash_bps;
+        }
+        if let Some(slash_bounty_bps) = update.slash_bounty_bps {
+            self.slash_bounty_bps = slash_bounty_bps;
+        }
+        if let Some(slash_stake_bps) = update.slash_stake_bps {
+            self.slash_stake_bps = slash_stake_bps;
+        }
+        if let Some(slash_repeat_loss_bps) = update.slash_repeat_loss_bps {
+            self.slash_repeat_loss_bps = slash_repeat_loss_bps;
+        }
+        if let Some(slash_max_multiplier_bps) = update.slash_max_multiplier_bps {
+            self.slash_max_multiplier_bps = slash_max_multiplier_bps;
+        }
+        if let Some(max_slash_lamports) = update.max_slash_lamports {
+            self.max_slash_lamports = max_slash_lamports;
+        }
+        if let Some(slash_client_bps) = update.slash_client_bps {
+            self.slash_client_bps = slash_client_bps;
+        }
+        if let Some(slash_treasury_bps) = update.slash_treasury_bps {
+            self.slash_treasury_bps = slash_treasury_bps;
+        }
+        if let Some(client_loss_fee_bps) = update.client_loss_fee_bps {
+            self.client_loss_fee_bps = client_loss_fee_bps;
+        }
+        if let Some(max_client_fee_bps) = update.max_client_fee_bps {
+            self.max_client_fee_bps = max_client_fee_bps;
+        }
+        if let Some(seating_window_seconds) = update.seating_window_seconds {
+            self.seating_window_seconds = seating_window_seconds;
+        }
+        if let Some(max_agent_name_len) = update.max_agent_name_len {
+            self.max_agent_name_len = max_agent_name_len;
+        }
+        if let Some(max_task_title_len) = update.max_task_title_len {
+            self.max_task_title_len = max_task_title_len;
+        }
+        
+        self.validate()
+    }
+    
+    /// Check parameters are within protocol bounds.
+    /// Name and title lengths can't exceed the account space allocated for them.
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.fee_bps <= BPS_DENOMINATOR,
+            AgentTrustError::InvalidConfig
+        );
+        
+        require!(
+            self.dispute_window_seconds > 0,
+            AgentTrustError::InvalidConfig
+        );
+        
+        require!(
+            self.unbonding_period_seconds >= 0,
+            AgentTrustError::InvalidConfig
+        );
+        
+        require!(
+            self.expiry_slash_bps <= BPS_DENOMINATOR,
+            AgentTrustError::InvalidConfig
+        );
+        
+        require!(
+            self.review_window_seconds > 0,
+            AgentTrustError::InvalidConfig
+        );
+        
+        require!(
+            self.panel_size % 2 == 1 &&
+            self.panel_size as usize <= MAX_PANEL_SIZE,
+            AgentTrustError::InvalidConfig
+        );
+        
+        require!(
+            self.commit_window_seconds > 0,
+            AgentTrustError::InvalidConfig
+        );
+        
+        require!(
+            self.reveal_window_seconds > 0,
+            AgentTrustError::InvalidConfig
+        );
+        
+        require!(
+            self.appeal_window_seconds >= 0,
+            AgentTrustError::InvalidConfig
+        );
+        
+        require!(
+            self.appeal_panel_size % 2 == 1 &&
+            self.appeal_panel_size as usize <= MAX_PANEL_SIZE,
+            AgentTrustError::InvalidConfig
+        );
+        
+        require!(
+            self.dispute_bond_bps <= BPS_DENOMINATOR,
+            AgentTrustError::InvalidConfig
+        );
+        
+        require!(
+            self.arbitrator_fee_bps <= BPS_DENOMINATOR,
+            AgentTrustError::InvalidConfig
+        );
+        
+        require!(
+            self.arbitrator_slash_bps <= BPS_DENOMINATOR,
+            AgentTrustError::InvalidConfig
+        );
+        
+        require!(
+            self.slash_bounty_bps <= BPS_DENOMINATOR,
+            AgentTrustError::InvalidConfig
+        );
+        
+        require!(
+            self.slash_stake_bps <= BPS_DENOMINATOR,
+            AgentTrustError::InvalidConfig
+        );
+        
+        require!(
+            self.slash_max_multiplier_bps >= BPS_DENOMINATOR,
+            AgentTrustError::InvalidConfig
+        );
+        
+        require!(
+            self.slash_client_bps.saturating_add(self.slash_treasury_bps) <= BPS_DENOMINATOR,
+            AgentTrustError::InvalidConfig
+        );
+        
+        require!(
+            self.client_loss_fee_bps <= BPS_DENOMINATOR,
+            AgentTrustError::InvalidConfig
+        );
+        
+        require!(
+            self.max_client_fee_bps >= self.fee_bps &&
+            self.max_client_fee_bps <= BPS_DENOMINATOR,
+            AgentTrustError::InvalidConfig
+        );
+        
+        require!(
+            self.seating_window_seconds > 0,
+            AgentTrustError::InvalidConfig
+        );
+        
+        require!(
+            self.max_agent_name_len > 0 &&
+            self.max_agent_name_len as usize <= MAX_AGENT_NAME_LEN,
+            AgentTrustError::InvalidConfig
+        );
+        
+        require!(
+            self.max_task_title_len > 0 &&
+            self.max_task_title_len as usize <= MAX_TASK_TITLE_LEN,
+            AgentTrustError::InvalidConfig
+        );
+        
+        Ok(())
+    }
+    
+    /// Slash for an agent that let a claimed task locking `collateral` expire: the
+    /// collateral plus a share of its free stake, up to the slash cap
+    pub fn calculate_expiry_slash(&self, agent: &Agent, collateral: u64) -> AgentSlash {
+        let amount = collateral
+            .saturating_add(apply_bps(agent.total_stake, self.expiry_slash_bps))
+            .min(self.max_slash_lamports);
+        
+        Self::take_slash(amount, agent, collateral)
+    }
+    
+    /// Slash for an agent that lost a dispute over `task` locking `collateral`, scaled by
+    /// the client's share of the ruling. The collateral counts toward the slash, so the
+    /// escalation with the agent's prior losses and the caps apply to all of it.
+    pub fn calculate_dispute_slash(
+        &self,
+        task: &Task,
+        agent: &Agent,
+        collateral: u64,
+        client_share_bps: u64,
+    ) -> AgentSlash {
+        // Token bounties aren't priced in lamports, so only the stake share applies
+        let bounty_part = match task.mint {
+            None => apply_bps(task.bounty, self.slash_bounty_bps),
+            Some(_) => 0,
+        };
+        let stake_part = apply_bps(agent.total_stake, self.slash_stake_bps);
+        
+        let multiplier = agent
+            .dispute_losses
+            .saturating_mul(self.slash_repeat_loss_bps)
+            .saturating_add(BPS_DENOMINATOR)
+            .min(self.slash_max_multiplier_bps);
+        let base = collateral
+            .saturating_add(bounty_part)
+            .saturating_add(stake_part);
+        let escalated = (base as u128 * multiplier as u128) / BPS_DENOMINATOR as u128;
+        let slash = escalated.min(self.max_slash_lamports as u128) as u64;
+        
+        Self::take_slash(apply_bps(slash, client_share_bps), agent, collateral)
+    }
+    
+    /// Take `amount` from the collateral first and the rest from free stake.
+    /// An agent holding less than the slash loses what it has.
+    fn take_slash(amount: u64, agent: &Agent, collateral: u64) -> AgentSlash {
+        let from_collateral = amount.min(collateral);
+        
+        AgentSlash {
+            collateral: from_collateral,
+            stake: (amount - from_collateral).min(agent.total_stake),
+        }
+    }
+    
+    /// Divide slashed lamports between the client, treasury and insurance pool
+    pub fn split_slash(&self, amount: u64) -> SlashSplit {
+        let client = apply_bps(amount, self.slash_client_bps);
+        let treasury = apply_bps(amount, self.slash_treasury_bps);
+        
+        SlashSplit {
+            client,
+            treasury,
+            insurance: amount - client - treasury,
+        }
+    }
+    
+    /// Lamports the filer of a dispute over `task` must bond
+    pub fn calculate_dispute_bond(&self, task: &Task) -> Result<u64> {
+        // Token bounties aren't priced in lamports, so they only pay the fixed part
+        let bounty_part = match task.mint {
+            None => apply_bps(task.bounty, self.dispute_bond_bps),
+            Some(_) => 0,
+        };
+        
+        self.dispute_bond_lamports
+            .checked_add(bounty_part)
+            .ok_or_else(|| error!(AgentTrustError::Overflow))
+    }
+    
+    /// Fee rate for a client who has lost `disputes_lost` disputes: each loss adds
+    /// a surcharge, up to the configured cap (basis points)
+    pub fn client_fee_bps(&self, disputes_lost: u64) -> u64 {
+        let surcharge = disputes_lost.saturating_mul(self.client_loss_fee_bps);
+        
+        self.fee_bps
+            .saturating_add(surcharge)
+            .min(self.max_client_fee_bps)
+    }
+    
+    /// Protocol fee for a bounty posted by a client who has lost `disputes_lost` disputes
+    pub fn calculate_fee(&self, bounty: u64, disputes_lost: u64) -> Result<u64> {
+        let fee = bounty
+            .checked_mul(self.client_fee_bps(disputes_lost))
+            .ok_or(AgentTrustError::FeeCalculationError)?
+            / BPS_DENOMINATOR;
+        Ok(fee)
+    }
+}
+
+/// Seeds for ProtocolConfig PDA: [b"config"]
+pub fn get_config_seeds() -> Vec<&'static [u8]> {
+    vec![b"config"]
+}
@@ -1,21 +1,89 @@
 use anchor_lang::prelude::*;
-use crate::{DisputeStatus, DisputeResolution, DISPUTE_WINDOW_SECONDS};
+use anchor_lang::solana_program::hash::hashv;
+use crate::{DisputeParty, DisputeStatus, DisputeResolution, PanelVote, MAX_PANEL_SIZE, MAX_VOTING_ROUNDS};
 use crate::errors::AgentTrustError;
+use crate::state::{ProtocolConfig, BPS_DENOMINATOR};
 
 #[account]
 pub struct Dispute {
     /// Associated task pubkey
     pub task: Pubkey,
     
-    /// Client's evidence IPFS hash (None until submitted)
-    pub client_evidence: Option<[u8; 32]>,
+    /// Wallet that opened the dispute
+    pub initiator: Pubkey,
     
-    /// Agent's evidence IPFS hash (None until submitted)
-    pub agent_evidence: Option<[u8; 32]>,
+    /// Whether the client or the agent opened the dispute
+    pub initiated_by: DisputeParty,
+    
+    /// Lamports bonded by the initiator and held by this account
+    pub filing_bond: u64,
+    
+    /// Evidence entries logged so far (next Evidence sequence number)
+    pub evidence_count: u64,
+    
+    /// Evidence entries submitted by the client
+    pub client_evidence_count: u32,
+    
+    /// Evidence entries submitted by the agent
+    pub agent_evidence_count: u32,
     
     /// Unix timestamp when dispute filed
     pub filed_at: i64,
     
+    /// Unix timestamp when the evidence period ends
+    pub evidence_deadline: i64,
+    
+    /// Slot at filing or appeal; the panel is drawn from the hash of the next slot
+    pub seating_slot: u64,
+    
+    /// Unix timestamp after which no panel can be seated and the dispute settles without one
+    pub seating_deadline: i64,
+    
+    /// Arbitrator authorities seated to decide the dispute (empty until seated)
+    pub panel: Vec<Pubkey>,
+    
+    /// Commit-reveal state of each panel seat
+    pub votes: Vec<PanelVote>,
+    
+    /// Revealed votes for the client this round
+    pub client_votes: u8,
+    
+    /// Revealed votes for the agent this round
+    pub agent_votes: u8,
+    
+    /// Revealed votes for a split this round
+    pub split_votes: u8,
+    
+    /// Voting round (a round without a majority is re-run up to MAX_VOTING_ROUNDS)
+    pub round: u8,
+    
+    /// Unix timestamp when the current voting round opened (0 until voting opens)
+    pub voting_opened_at: i64,
+    
+    /// Unix timestamp when the commit phase ends (0 until voting opens)
+    pub commit_deadline: i64,
+    
+    /// Unix timestamp when the reveal phase ends (0 until voting opens)
+    pub reveal_deadline: i64,
+    
+    /// Panel that decided the appealed ruling (empty unless appealed)
+    pub prior_panel: Vec<Pubkey>,
+    
+    /// Votes of the prior panel (empty unless appealed)
+    pub prior_votes: Vec<PanelVote>,
+    
+    /// Ruling under appeal (None unless appealed)
+    pub appealed_ruling: Option<DisputeResolution>,
+    
+    /// Party that appealed (None unless appealed)
+    pub appellant: Option<Pubkey>,
+    
+    /// Lamports bonded by the appellant and held by this account
+    pub appeal_bond: u64,
+    
+    /// Unix timestamp until which the ruling can be appealed
+    pub appeal_deadline: i64,
+    
     /// Unix timestamp when resolved (None until resolved)
     pub resolved_at: Option<i64>,
     
@@ -31,20 +99,60 @@ pub struct Dispute {
 
 impl Dispute {
     /// Calculate space needed for Dispute account
-    /// 8 (discriminator) + 32 (task) + 33 (client evidence option) + 33 (agent evidence option) +
-    /// 8 (filed_at) + 9 (resolved option) + 2 (resolution option) + 1 (status) + 1 (bump)
-    pub const SPACE: usize = 8 + 32 + 33 + 33 + 8 + 9 + 2 + 1 + 1;
+    /// 8 (discriminator) + 32 (task) + 32 (initiator) + 1 (initiated_by) + 8 (filing bond) + 8 (evidence count) + 4 (client evidence count) + 4 (agent evidence count) +
+    /// 8 (filed_at) + 8 (evidence_deadline) + 8 (seating slot) + 8 (seating deadline) + 4 + 32 * MAX_PANEL_SIZE (panel) + 4 + 33 * MAX_PANEL_SIZE (votes) +
+    /// 1 (client votes) + 1 (agent votes) + 1 (split votes) + 1 (round) + 8 (voting opened) + 8 (commit deadline) +
+    /// 8 (reveal deadline) + 4 + 32 * MAX_PANEL_SIZE (prior panel) + 4 + 33 * MAX_PANEL_SIZE (prior votes) +
+    /// 4 (appealed ruling option) + 33 (appellant option) + 8 (appeal bond) + 8 (appeal deadline) +
+    /// 9 (resolved option) + 4 (resolution option) + 1 (status) + 1 (bump)
+    pub const SPACE: usize = 8 + 32 + 32 + 1 + 8 + 8 + 4 + 4 + 8 + 8 + 8 + 8 + 4 + 32 * MAX_PANEL_SIZE + 4 + 33 * MAX_PANEL_SIZE +
+        1 + 1 + 1 + 1 + 8 + 8 + 8 + 4 + 32 * MAX_PANEL_SIZE + 4 + 33 * MAX_PANEL_SIZE + 4 + 33 + 8 + 8 +
+        9 + 4 + 1 + 1;
     
-    /// Create a new dispute
+    /// Create a new dispute; its panel is seated once a later slot hash is known
     pub fn create(
         &mut self,
         task: Pubkey,
+        initiator: Pubkey,
+        initiated_by: DisputeParty,
+        filing_bond: u64,
+        config: &ProtocolConfig,
         bump: u8,
     ) -> Result<()> {
+        let clock = Clock::get()?;
+        let now = clock.unix_timestamp;
+        
         self.task = task;
-        self.client_evidence = None;
-        self.agent_evidence = None;
-        self.filed_at = Clock::get()?.unix_timestamp;
+        self.initiator = initiator;
+        self.initiated_by = initiated_by;
+        self.filing_bond = filing_bond;
+        self.evidence_count = 0;
+        self.client_evidence_count = 0;
+        self.agent_evidence_count = 0;
+        self.filed_at = now;
+        self.evidence_deadline = now
+            .checked_add(config.dispute_window_seconds)
+            .ok_or(AgentTrustError::Overflow)?;
+        self.seating_slot = clock.slot;
+        self.seating_deadline = self
+            .evidence_deadline
+            .checked_add(config.seating_window_seconds)
+            .ok_or(AgentTrustError::Overflow)?;
+        self.panel = Vec::new();
+        self.votes = Vec::new();
+        self.client_votes = 0;
+        self.agent_votes = 0;
+        self.split_votes = 0;
+        self.round = 0;
+        self.voting_opened_at = 0;
+        self.commit_deadline = 0;
+        self.reveal_deadline = 0;
+        self.prior_panel = Vec::new();
+        self.prior_votes = Vec::new();
+        self.appealed_ruling = None;
+        self.appellant = None;
+        self.appeal_bond = 0;
+        self.appeal_deadline = 0;
         self.resolved_at = None;
         self.resolution = None;
         self.status = DisputeStatus::EvidencePeriod;
@@ -53,12 +161,8 @@ impl Dispute {
         Ok(())
     }
     
-    /// Submit evidence (can be called by client or agent)
-    pub fn submit_evidence(
-        &mut self,
-        evidence_hash: [u8; 32],
-        is_client: bool,
-    ) -> Result<()> {
+    /// Log an evidence entry from the client or agent, returning its sequence number
+    pub fn record_evidence(&mut self, is_client: bool) -> Result<u64> {
         require!(
             self.status == DisputeStatus::EvidencePeriod,
             AgentTrustError::DisputeNotInEvidencePeriod
@@ -69,47 +173,493 @@ impl Dispute {
             AgentTrustError::EvidencePeriodOver
         );
         
+        let sequence = self.evidence_count;
+        self.evidence_count = sequence
+            .checked_add(1)
+            .ok_or(AgentTrustError::Overflow)?;
+        
         if is_client {
-            require!(
-                self.client_evidence.is_none(),
-                AgentTrustError::EvidenceAlreadySubmitted
-            );
-            self.client_evidence = Some(evidence_hash);
+            self.client_evidence_count += 1;
         } else {
-            require!(
-                self.agent_evidence.is_none(),
-                AgentTrustError::EvidenceAlreadySubmitted
-            );
-            self.agent_evidence = Some(evidence_hash);
+            self.agent_evidence_count += 1;
         }
         
-        Ok(())
+        Ok(sequence)
     }
     
-    /// Resolve the dispute (called by AI/authority)
-    pub fn resolve(&mut self, resolution: DisputeResolution) -> Result<()> {
+    /// Seat the arbitrators drawn for this dispute
+    pub fn seat(&mut self, panel: Vec<Pubkey>) -> Result<()> {
+        require!(
+            self.panel.is_empty(),
+            AgentTrustError::PanelAlreadySeated
+        );
+        
         require!(
             self.status == DisputeStatus::EvidencePeriod || 
             self.status == DisputeStatus::JudgmentPending,
-            AgentTrustError::DisputeAlreadyResolved
+            AgentTrustError::InvalidDisputeStatus
+        );
+        
+        require!(
+            !Self::has_passed(self.seating_deadline),
+            AgentTrustError::SeatingWindowClosed
+        );
+        
+        self.votes = vec![PanelVote::Pending; panel.len()];
+        self.panel = panel;
+        
+        Ok(())
+    }
+    
+    /// Draw the panel after the current slot instead, once the seating slot has
+    /// dropped out of the SlotHashes window
+    pub fn restart_seating(&mut self) -> Result<()> {
+        require!(
+            self.panel.is_empty(),
+            AgentTrustError::PanelAlreadySeated
         );
         
-        // Ensure evidence period is over or both parties submitted
-        if self.status == DisputeStatus::EvidencePeriod {
+        self.seating_slot = Clock::get()?.slot;
+        
+        Ok(())
+    }
+    
+    /// Wallets that can't sit on this dispute's panel: the task's parties and, on
+    /// appeal, the arbitrators who ruled
+    pub fn panel_exclusions(&self, client: Pubkey, agent: Option<Pubkey>) -> Vec<Pubkey> {
+        let mut excluded = vec![client];
+        excluded.extend(agent);
+        excluded.extend(self.prior_panel.iter().copied());
+        excluded
+    }
+    
+    /// Settle without a panel once the seating deadline has passed with none seated.
+    /// An appeal lapses, leaving the appealed ruling with the panel that reached it;
+    /// a first dispute takes the fallback ruling. Either way the bond of the party
+    /// waiting on the panel is returned in full. Returns that party and its bond.
+    pub fn lapse_seating(&mut self) -> Result<(Pubkey, u64)> {
+        require!(
+            self.status == DisputeStatus::EvidencePeriod ||
+            self.status == DisputeStatus::JudgmentPending,
+            AgentTrustError::InvalidDisputeStatus
+        );
+        
+        require!(
+            self.panel.is_empty(),
+            AgentTrustError::PanelAlreadySeated
+        );
+        
+        require!(
+            Self::has_passed(self.seating_deadline),
+            AgentTrustError::SeatingWindowOpen
+        );
+        
+        let refund = match (self.appellant.take(), self.appealed_ruling.take()) {
+            (Some(appellant), Some(ruling)) => {
+                self.panel = std::mem::take(&mut self.prior_panel);
+                self.votes = std::mem::take(&mut self.prior_votes);
+                self.resolution = Some(ruling);
+                (appellant, std::mem::take(&mut self.appeal_bond))
+            }
+            _ => {
+                self.resolution = Some(self.fallback_ruling());
+                (self.initiator, std::mem::take(&mut self.filing_bond))
+            }
+        };
+        
+        let now = Clock::get()?.unix_timestamp;
+        self.resolved_at = Some(now);
+        // Already passed: nobody could be seated to hear an appeal either
+        self.appeal_deadline = now.saturating_sub(1);
+        self.status = DisputeStatus::Resolved;
+        
+        Ok(refund)
+    }
+    
+    /// Number of seats to fill: appeals are heard by a larger panel
+    pub fn panel_size(&self, config: &ProtocolConfig) -> usize {
+        if self.appellant.is_some() {
+            config.appeal_panel_size as usize
+        } else {
+            config.panel_size as usize
+        }
+    }
+    
+    /// Seal a panel arbitrator's vote. The first commit opens voting on a dispute
+    /// awaiting judgment (queued by close_evidence_period or reopened by an appeal).
+    pub fn commit_vote(
+        &mut self,
+        arbitrator: Pubkey,
+        commitment: [u8; 32],
+        config: &ProtocolConfig,
+    ) -> Result<()> {
+        require!(
+            !self.panel.is_empty(),
+            AgentTrustError::PanelNotSeated
+        );
+        
+        if self.status == DisputeStatus::JudgmentPending {
+            self.open_voting(config)?;
+        }
+        
+        require!(
+            self.status == DisputeStatus::VoteCommit && 
+            !Self::has_passed(self.commit_deadline),
+            AgentTrustError::CommitPhaseClosed
+        );
+        
+        let seat = self.seat_of(&arbitrator)?;
+        
+        require!(
+            self.votes[seat] == PanelVote::Pending,
+            AgentTrustError::AlreadyVoted
+        );
+        
+        self.votes[seat] = PanelVote::Committed { commitment };
+        
+        Ok(())
+    }
+    
+    /// Open a seat's sealed vote; the round is tallied once no commitments are left sealed.
+    /// Once the commit deadline passes, seats that never committed must first be
+    /// forfeited (and penalized) through `close_phase`.
+    pub fn reveal_vote(
+        &mut self,
+        arbitrator: Pubkey,
+        resolution: DisputeResolution,
+        salt: [u8; 32],
+        config: &ProtocolConfig,
+    ) -> Result<()> {
+        // Reveals start early once every open seat has committed
+        if self.status == DisputeStatus::VoteCommit {
+            if self.votes.contains(&PanelVote::Pending) {
+                return if Self::has_passed(self.commit_deadline) {
+                    err!(AgentTrustError::CommitPhaseNotClosed)
+                } else {
+                    err!(AgentTrustError::CommitPhaseOpen)
+                };
+            }
+            
+            self.status = DisputeStatus::VoteReveal;
+        }
+        
+        require!(
+            self.status == DisputeStatus::VoteReveal && 
+            !Self::has_passed(self.reveal_deadline),
+            AgentTrustError::RevealPhaseClosed
+        );
+        
+        if let DisputeResolution::Split { agent_bps } = resolution {
             require!(
-                self.is_evidence_period_over() || 
-                (self.client_evidence.is_some() && self.agent_evidence.is_some()),
-                AgentTrustError::EvidencePeriodActive
+                agent_bps > 0 && (agent_bps as u64) < BPS_DENOMINATOR,
+                AgentTrustError::InvalidSplit
             );
         }
         
-        self.resolution = Some(resolution);
-        self.resolved_at = Some(Clock::get()?.unix_timestamp);
+        let seat = self.seat_of(&arbitrator)?;
+        
+        match self.votes[seat] {
+            PanelVote::Committed { commitment } => require!(
+                commitment == Self::vote_commitment(&arbitrator, resolution, &salt)?,
+                AgentTrustError::InvalidReveal
+            ),
+            _ => return err!(AgentTrustError::InvalidReveal),
+        }
+        
+        self.votes[seat] = PanelVote::Revealed { resolution };
+        match resolution {
+            DisputeResolution::ClientWins => self.client_votes += 1,
+            DisputeResolution::AgentWins => self.agent_votes += 1,
+            DisputeResolution::Split { .. } => self.split_votes += 1,
+        }
+        
+        if !self.has_sealed_votes() {
+            self.tally(config)?;
+        }
+        
+        Ok(())
+    }
+    
+    /// Close a voting phase once its deadline passes: seats that never committed are
+    /// forfeited after the commit deadline, and seats that never revealed after the
+    /// reveal deadline. Returns the authorities whose seats were forfeited.
+    pub fn close_phase(&mut self, config: &ProtocolConfig) -> Result<Vec<Pubkey>> {
+        match self.status {
+            DisputeStatus::VoteCommit => require!(
+                Self::has_passed(self.commit_deadline),
+                AgentTrustError::CommitPeriodActive
+            ),
+            DisputeStatus::VoteReveal => require!(
+                Self::has_passed(self.reveal_deadline),
+                AgentTrustError::RevealPeriodActive
+            ),
+            _ => return err!(AgentTrustError::InvalidDisputeStatus),
+        }
+        
+        let reveal_over = Self::has_passed(self.reveal_deadline);
+        let forfeited = self.forfeit_seats(|vote| match vote {
+            PanelVote::Pending => true,
+            PanelVote::Committed { .. } => reveal_over,
+            _ => false,
+        });
+        self.status = DisputeStatus::VoteReveal;
+        
+        if !self.has_sealed_votes() {
+            self.tally(config)?;
+        }
+        
+        Ok(forfeited)
+    }
+    
+    /// Resolve on a majority of the full panel. Without one the round is re-run while
+    /// the seats left can still form a majority, up to MAX_VOTING_ROUNDS rounds;
+    /// otherwise the fallback ruling applies so the dispute can't stall.
+    fn tally(&mut self, config: &ProtocolConfig) -> Result<()> {
+        let ruling = match self.majority() {
+            Some(ruling) => ruling,
+            None if self.can_rerun() => return self.rerun(config),
+            None => self.fallback_ruling(),
+        };
+        
+        let now = Clock::get()?.unix_timestamp;
+        
+        // An appeal ruling is final; a first ruling can still be appealed
+        self.appeal_deadline = if self.appellant.is_some() {
+            now
+        } else {
+            now
+                .checked_add(config.appeal_window_seconds)
+                .ok_or(AgentTrustError::Overflow)?
+        };
+        self.resolution = Some(ruling);
+        self.resolved_at = Some(now);
+        self.status = DisputeStatus::Resolved;
+        
+        Ok(())
+    }
+    
+    /// Whether another round could still reach a majority of the full panel
+    fn can_rerun(&self) -> bool {
+        let seated = self
+            .votes
+            .iter()
+            .filter(|vote| **vote != PanelVote::Forfeited)
+            .count();
+        
+        seated * 2 > self.panel.len() && self.round + 1 < MAX_VOTING_ROUNDS
+    }
+    
+    /// Ruling when the panel can't reach a majority: an appealed ruling stands,
+    /// otherwise the bounty is split evenly
+    pub fn fallback_ruling(&self) -> DisputeResolution {
+        self.appealed_ruling.unwrap_or(DisputeResolution::Split {
+            agent_bps: (BPS_DENOMINATOR / 2) as u16,
+        })
+    }
+    
+    /// Open a new round for every seat that hasn't been forfeited
+    fn rerun(&mut self, config: &ProtocolConfig) -> Result<()> {
+        for vote in self.votes.iter_mut() {
+            if *vote != PanelVote::Forfeited {
+                *vote = PanelVote::Pending;
+            }
+        }
+        self.client_votes = 0;
+        self.agent_votes = 0;
+        self.split_votes = 0;
+        self.round = self.round.checked_add(1).ok_or(AgentTrustError::Overflow)?;
+        
+        self.open_voting(config)
+    }
+    
+    /// Rule for the only party that submitted evidence once the evidence period is over.
+    /// Applies until the dispute is resolved, but not to appeals; the ruling is final.
+    pub fn default_judgment(&mut self) -> Result<DisputeResolution> {
+        require!(
+            self.status != DisputeStatus::Resolved && self.appellant.is_none(),
+            AgentTrustError::DisputeAlreadyResolved
+        );
+        
+        require!(
+            self.is_evidence_period_over(),
+            AgentTrustError::EvidencePeriodActive
+        );
+        
+        let ruling = match (self.client_evidence_count > 0, self.agent_evidence_count > 0) {
+            (true, false) => DisputeResolution::ClientWins,
+            (false, true) => DisputeResolution::AgentWins,
+            _ => return err!(AgentTrustError::NoDefaultJudgment),
+        };
+        
+        let now = Clock::get()?.unix_timestamp;
+        self.resolution = Some(ruling);
+        self.resolved_at = Some(now);
+        // Already passed, so the ruling can't be appealed even within the same second
+        self.appeal_deadline = now.saturating_sub(1);
         self.status = DisputeStatus::Resolved;
         
+        Ok(ruling)
+    }
+    
+    /// Reopen a resolved dispute before a new panel, seated once a later slot hash is
+    /// known. Only a party the ruling went against can appeal, once, within the appeal window.
+    pub fn appeal(
+        &mut self,
+        appellant: Pubkey,
+        is_client: bool,
+        bond: u64,
+        config: &ProtocolConfig,
+    ) -> Result<()> {
+        require!(
+            self.status == DisputeStatus::Resolved,
+            AgentTrustError::DisputeNotResolved
+        );
+        
+        require!(
+            self.appellant.is_none(),
+            AgentTrustError::AlreadyAppealed
+        );
+        
+        require!(
+            !self.is_appeal_window_over(),
+            AgentTrustError::AppealWindowClosed
+        );
+        
+        let ruling = self.resolution.ok_or(AgentTrustError::DisputeNotResolved)?;
+        let lost = match ruling {
+            DisputeResolution::ClientWins => !is_client,
+            DisputeResolution::AgentWins => is_client,
+            DisputeResolution::Split { .. } => true,
+        };
+        require!(lost, AgentTrustError::NotLosingParty);
+        
+        self.appealed_ruling = Some(ruling);
+        self.appellant = Some(appellant);
+        self.appeal_bond = bond;
+        self.prior_votes = std::mem::take(&mut self.votes);
+        self.prior_panel = std::mem::take(&mut self.panel);
+        let clock = Clock::get()?;
+        self.seating_slot = clock.slot;
+        self.seating_deadline = clock
+            .unix_timestamp
+            .checked_add(config.seating_window_seconds)
+            .ok_or(AgentTrustError::Overflow)?;
+        self.client_votes = 0;
+        self.agent_votes = 0;
+        self.split_votes = 0;
+        self.round = 0;
+        self.resolution = None;
+        self.resolved_at = None;
+        self.status = DisputeStatus::JudgmentPending;
+        
         Ok(())
     }
     
+    /// Share of the filing bond returned to the initiator (basis points); the rest
+    /// goes to the counterparty
+    pub fn filing_bond_refund_bps(&self) -> u64 {
+        match (self.initiated_by, self.resolution) {
+            (DisputeParty::Client, Some(DisputeResolution::ClientWins)) |
+            (DisputeParty::Agent, Some(DisputeResolution::AgentWins)) => BPS_DENOMINATOR,
+            (DisputeParty::Client, Some(DisputeResolution::Split { agent_bps })) => {
+                BPS_DENOMINATOR - agent_bps as u64
+            }
+            (DisputeParty::Agent, Some(DisputeResolution::Split { agent_bps })) => agent_bps as u64,
+            _ => 0,
+        }
+    }
+    
+    /// Whether the appeal ruling left the appealed ruling in place (None if not appealed)
+    pub fn appeal_upheld(&self) -> Option<bool> {
+        self.appealed_ruling.map(|ruling| self.resolution == Some(ruling))
+    }
+    
+    /// Check if the ruling can no longer be appealed
+    pub fn is_appeal_window_over(&self) -> bool {
+        Self::has_passed(self.appeal_deadline)
+    }
+    
+    /// Start a commit phase with fresh deadlines
+    fn open_voting(&mut self, config: &ProtocolConfig) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        
+        self.voting_opened_at = now;
+        self.commit_deadline = now
+            .checked_add(config.commit_window_seconds)
+            .ok_or(AgentTrustError::Overflow)?;
+        self.reveal_deadline = self
+            .commit_deadline
+            .checked_add(config.reveal_window_seconds)
+            .ok_or(AgentTrustError::Overflow)?;
+        self.status = DisputeStatus::VoteCommit;
+        
+        Ok(())
+    }
+    
+    /// Resolution backed by a strict majority of the full panel, if any; forfeited and
+    /// unrevealed seats count against every ruling. Split votes only count together
+    /// when they agree on the same share.
+    pub fn majority(&self) -> Option<DisputeResolution> {
+        let revealed: Vec<DisputeResolution> = self
+            .votes
+            .iter()
+            .filter_map(|vote| match vote {
+                PanelVote::Revealed { resolution } => Some(*resolution),
+                _ => None,
+            })
+            .collect();
+        
+        revealed.iter().copied().find(|candidate| {
+            revealed.iter().filter(|resolution| *resolution == candidate).count() * 2 > self.panel.len()
+        })
+    }
+    
+    /// Commitment an arbitrator submits: hash(resolution || salt || arbitrator)
+    pub fn vote_commitment(
+        arbitrator: &Pubkey,
+        resolution: DisputeResolution,
+        salt: &[u8; 32],
+    ) -> Result<[u8; 32]> {
+        let encoded = resolution.try_to_vec()?;
+        
+        Ok(hashv(&[&encoded, salt, arbitrator.as_ref()]).to_bytes())
+    }
+    
+    /// Forfeit every seat whose vote matches `missed`, returning the seats' authorities
+    fn forfeit_seats(&mut self, missed: impl Fn(&PanelVote) -> bool) -> Vec<Pubkey> {
+        let mut forfeited = Vec::new();
+        for (seat, vote) in self.votes.iter_mut().enumerate() {
+            if missed(vote) {
+                *vote = PanelVote::Forfeited;
+                forfeited.push(self.panel[seat]);
+            }
+        }
+        
+        forfeited
+    }
+    
+    /// Whether any seat has committed but not yet revealed
+    fn has_sealed_votes(&self) -> bool {
+        self.votes
+            .iter()
+            .any(|vote| matches!(vote, PanelVote::Committed { .. }))
+    }
+    
+    /// Panel seat index held by an arbitrator
+    fn seat_of(&self, arbitrator: &Pubkey) -> Result<usize> {
+        self.panel
+            .iter()
+            .position(|member| member == arbitrator)
+            .ok_or_else(|| error!(AgentTrustError::NotPanelArbitrator))
+    }
+    
+    /// Check if a phase deadline is behind us
+    fn has_passed(deadline: i64) -> bool {
+        Clock::get()
+            .map(|c| c.unix_timestamp > deadline)
+            .unwrap_or(false)
+    }
+    
     /// Move to judgment pending (when evidence period ends)
     pub fn move_to_judgment(&mut self) -> Result<()> {
         require!(
@@ -133,7 +683,7 @@ impl Dispute {
             .map(|c| c.unix_timestamp)
             .unwrap_or(0);
         
-        current_time > self.filed_at + DISPUTE_WINDOW_SECONDS
+        current_time > self.evidence_deadline
     }
     
     /// Get time remaining in evidence period (seconds)
@@ -142,8 +692,7 @@ impl Dispute {
             .map(|c| c.unix_timestamp)
             .unwrap_or(0);
         
-        let end_time = self.filed_at + DISPUTE_WINDOW_SECONDS;
-        (end_time - current_time).max(0)
+        (self.evidence_deadline - current_time).max(0)
     }
 }
 
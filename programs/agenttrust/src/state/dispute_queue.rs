@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::MAX_QUEUED_DISPUTES;
+
+/// Ring buffer of the disputes whose evidence period closed most recently, so judges
+/// can find them without scanning every account. Entries aren't removed on
+/// settlement; judges skip the ones that are no longer awaiting a ruling.
+#[account]
+pub struct DisputeQueue {
+    /// Dispute PDAs ready for judgment, at most MAX_QUEUED_DISPUTES
+    pub disputes: Vec<Pubkey>,
+    
+    /// Slot the next dispute is written to; once the queue is full this is the oldest entry
+    pub next: u32,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl DisputeQueue {
+    /// Calculate space needed for DisputeQueue account
+    /// 8 (discriminator) + 4 + 32 * MAX_QUEUED_DISPUTES (disputes) + 4 (next) + 1 (bump)
+    pub const SPACE: usize = 8 + 4 + 32 * MAX_QUEUED_DISPUTES + 4 + 1;
+    
+    /// Add a dispute that is ready for judgment, replacing the oldest entry once full
+    pub fn push(&mut self, dispute: Pubkey) {
+        if self.disputes.contains(&dispute) {
+            return;
+        }
+        
+        let slot = self.next as usize;
+        if slot < self.disputes.len() {
+            self.disputes[slot] = dispute;
+        } else {
+            self.disputes.push(dispute);
+        }
+        self.next = ((slot + 1) % MAX_QUEUED_DISPUTES) as u32;
+    }
+}
+
+/// Seeds for DisputeQueue PDA: [b"dispute_queue"]
+pub fn get_dispute_queue_seeds() -> Vec<&'static [u8]> {
+    vec![b"dispute_queue"]
+}
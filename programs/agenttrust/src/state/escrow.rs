@@ -0,0 +1,228 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    transfer_fee::TransferFeeAmount, BaseStateWithExtensions, StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::state::Account as SplTokenAccount;
+use anchor_spl::token_interface::{self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::errors::AgentTrustError;
+use crate::state::{AgentSlash, InsurancePool, ProtocolConfig, SlashSplit, StakeVault, Treasury};
+use crate::utils::move_lamports;
+
+#[account]
+pub struct Escrow {
+    /// Task this escrow holds funds for
+    pub task: Pubkey,
+    
+    /// SPL mint of the escrowed funds (None for lamports)
+    pub mint: Option<Pubkey>,
+    
+    /// Amount held for the task (bounty + fee), excluding rent
+    pub balance: u64,
+    
+    /// Agent collateral held in lamports, on top of the balance
+    pub collateral: u64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// Token accounts needed to pay out of a mint-denominated escrow
+pub struct EscrowVault<'a, 'info> {
+    pub vault: &'a InterfaceAccount<'info, TokenAccount>,
+    pub mint: &'a InterfaceAccount<'info, Mint>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+}
+
+impl<'a, 'info> EscrowVault<'a, 'info> {
+    /// Collect the optional token accounts of an instruction, if all are present
+    pub fn from_accounts(
+        vault: &'a Option<InterfaceAccount<'info, TokenAccount>>,
+        mint: &'a Option<InterfaceAccount<'info, Mint>>,
+        token_program: &'a Option<Interface<'info, TokenInterface>>,
+    ) -> Option<Self> {
+        Some(Self {
+            vault: vault.as_ref()?,
+            mint: mint.as_ref()?,
+            token_program: token_program.as_ref()?,
+        })
+    }
+}
+
+impl Escrow {
+    /// Calculate space needed for Escrow account
+    /// 8 (discriminator) + 32 (task) + 33 (mint option) + 8 (balance) + 8 (collateral) + 1 (bump)
+    pub const SPACE: usize = 8 + 32 + 33 + 8 + 8 + 1;
+    
+    /// Pay out of the escrow in whatever asset it holds.
+    /// `wallet` receives lamports; `token_account` receives tokens for mint-denominated tasks.
+    pub fn pay<'info>(
+        escrow: &mut Account<'info, Escrow>,
+        vault: Option<&EscrowVault<'_, 'info>>,
+        wallet: &AccountInfo<'info>,
+        token_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+        amount: u64,
+    ) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        
+        match escrow.mint {
+            None => Self::release(escrow, wallet, amount),
+            Some(_) => {
+                let vault = vault.ok_or(AgentTrustError::MissingTokenAccounts)?;
+                let token_account = token_account.ok_or(AgentTrustError::MissingTokenAccounts)?;
+                Self::release_tokens(escrow, vault, token_account, amount)
+            }
+        }
+    }
+    
+    /// Pay lamports out of the escrow PDA and keep `balance` in sync
+    pub fn release<'info>(
+        escrow: &mut Account<'info, Escrow>,
+        recipient: &AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<()> {
+        escrow.balance = escrow
+            .balance
+            .checked_sub(amount)
+            .ok_or(AgentTrustError::InsufficientFunds)?;
+        
+        move_lamports(&escrow.to_account_info(), recipient, amount)
+    }
+    
+    /// Lock an agent's collateral in the escrow, taken from its stake vault
+    pub fn lock_collateral<'info>(
+        escrow: &mut Account<'info, Escrow>,
+        stake_vault: &Account<'info, StakeVault>,
+        amount: u64,
+    ) -> Result<()> {
+        StakeVault::withdraw(stake_vault, &escrow.to_account_info(), amount)?;
+        
+        escrow.collateral = escrow
+            .collateral
+            .checked_add(amount)
+            .ok_or(AgentTrustError::Overflow)?;
+        
+        Ok(())
+    }
+    
+    /// Pay locked collateral out of the escrow: back to the stake vault, or to the client when slashed
+    pub fn release_collateral<'info>(
+        escrow: &mut Account<'info, Escrow>,
+        recipient: &AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<()> {
+        escrow.collateral = escrow
+            .collateral
+            .checked_sub(amount)
+            .ok_or(AgentTrustError::InsufficientFunds)?;
+        
+        move_lamports(&escrow.to_account_info(), recipient, amount)
+    }
+    
+    /// Carry out an agent slash: the slashed stake joins the forfeited collateral in the
+    /// escrow, and the total is split between the client, treasury and insurance pool
+    pub fn pay_slash<'info>(
+        escrow: &mut Account<'info, Escrow>,
+        stake_vault: &Account<'info, StakeVault>,
+        slash: &AgentSlash,
+        config: &ProtocolConfig,
+        client: &AccountInfo<'info>,
+        treasury: &mut Account<'info, Treasury>,
+        insurance_pool: &mut Account<'info, InsurancePool>,
+    ) -> Result<SlashSplit> {
+        escrow.collateral = escrow
+            .collateral
+            .checked_sub(slash.collateral)
+            .ok_or(AgentTrustError::InsufficientFunds)?;
+        StakeVault::withdraw(stake_vault, &escrow.to_account_info(), slash.stake)?;
+        
+        let split = config.split_slash(slash.total());
+        let from = escrow.to_account_info();
+        move_lamports(&from, client, split.client)?;
+        move_lamports(&from, &treasury.to_account_info(), split.treasury)?;
+        treasury.record_slash(split.treasury)?;
+        move_lamports(&from, &insurance_pool.to_account_info(), split.insurance)?;
+        insurance_pool.record_deposit(split.insurance)?;
+        
+        Ok(split)
+    }
+    
+    /// Pay tokens out of the escrow vault, signed by the escrow PDA.
+    /// Token-2022 transfer fees are withheld from the recipient.
+    pub fn release_tokens<'info>(
+        escrow: &mut Account<'info, Escrow>,
+        vault: &EscrowVault<'_, 'info>,
+        recipient: &InterfaceAccount<'info, TokenAccount>,
+        amount: u64,
+    ) -> Result<()> {
+        require!(
+            escrow.mint == Some(vault.mint.key())
+                && vault.vault.mint == vault.mint.key()
+                && recipient.mint == vault.mint.key(),
+            AgentTrustError::InvalidMint
+        );
+        
+        escrow.balance = escrow
+            .balance
+            .checked_sub(amount)
+            .ok_or(AgentTrustError::InsufficientFunds)?;
+        
+        let task = escrow.task;
+        let bump = [escrow.bump];
+        let signer_seeds: &[&[&[u8]]] = &[&[b"escrow", task.as_ref(), &bump]];
+        
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                vault.token_program.to_account_info(),
+                TransferChecked {
+                    from: vault.vault.to_account_info(),
+                    mint: vault.mint.to_account_info(),
+                    to: recipient.to_account_info(),
+                    authority: escrow.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+            vault.mint.decimals,
+        )
+    }
+    
+    /// Close an emptied token vault, returning its rent to `destination`.
+    /// Token-2022 vaults still holding withheld transfer fees can't be closed
+    /// until those are harvested to the mint, so they are left open.
+    pub fn close_vault<'info>(
+        escrow: &Account<'info, Escrow>,
+        vault: &EscrowVault<'_, 'info>,
+        destination: &AccountInfo<'info>,
+    ) -> Result<()> {
+        if has_withheld_fees(&vault.vault.to_account_info())? {
+            msg!("Escrow vault holds withheld transfer fees; left open");
+            return Ok(());
+        }
+        
+        let task = escrow.task;
+        let bump = [escrow.bump];
+        let signer_seeds: &[&[&[u8]]] = &[&[b"escrow", task.as_ref(), &bump]];
+        
+        token_interface::close_account(CpiContext::new_with_signer(
+            vault.token_program.to_account_info(),
+            CloseAccount {
+                account: vault.vault.to_account_info(),
+                destination: destination.clone(),
+                authority: escrow.to_account_info(),
+            },
+            signer_seeds,
+        ))
+    }
+}
+
+/// Whether a Token-2022 account holds transfer fees awaiting harvest
+fn has_withheld_fees(account: &AccountInfo) -> Result<bool> {
+    let data = account.try_borrow_data()?;
+    let state = StateWithExtensions::<SplTokenAccount>::unpack(&data)?;
+    Ok(state
+        .get_extension::<TransferFeeAmount>()
+        .map(|fees| u64::from(fees.withheld_amount) > 0)
+        .unwrap_or(false))
+}
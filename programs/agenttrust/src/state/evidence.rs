@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::EvidenceType;
+
+/// One entry in a dispute's append-only evidence log
+#[account]
+pub struct Evidence {
+    /// Dispute this entry belongs to
+    pub dispute: Pubkey,
+    
+    /// Position in the dispute's evidence log (used in the PDA seeds)
+    pub sequence: u64,
+    
+    /// Client or agent wallet that submitted the entry
+    pub submitter: Pubkey,
+    
+    /// IPFS hash of the evidence content
+    pub content_hash: [u8; 32],
+    
+    /// Kind of content the hash points to
+    pub content_type: EvidenceType,
+    
+    /// Unix timestamp when submitted
+    pub submitted_at: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Evidence {
+    /// Calculate space needed for Evidence account
+    /// 8 (discriminator) + 32 (dispute) + 8 (sequence) + 32 (submitter) + 32 (content hash) +
+    /// 1 (content type) + 8 (submitted_at) + 1 (bump)
+    pub const SPACE: usize = 8 + 32 + 8 + 32 + 32 + 1 + 8 + 1;
+    
+    /// Record a new evidence entry
+    pub fn record(
+        &mut self,
+        dispute: Pubkey,
+        sequence: u64,
+        submitter: Pubkey,
+        content_hash: [u8; 32],
+        content_type: EvidenceType,
+        bump: u8,
+    ) -> Result<()> {
+        self.dispute = dispute;
+        self.sequence = sequence;
+        self.submitter = submitter;
+        self.content_hash = content_hash;
+        self.content_type = content_type;
+        self.submitted_at = Clock::get()?.unix_timestamp;
+        self.bump = bump;
+        
+        Ok(())
+    }
+}
+
+/// Seeds for Evidence PDA: [b"evidence", dispute_pubkey, sequence (little-endian u64)]
+pub fn get_evidence_seeds<'a>(dispute: &'a Pubkey, sequence: &'a [u8; 8]) -> Vec<&'a [u8]> {
+    vec![b"evidence", dispute.as_ref(), sequence]
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::errors::AgentTrustError;
+
+/// Share of slashed agent stake set aside for client insurance
+#[account]
+pub struct InsurancePool {
+    /// Lamports paid in over the protocol's lifetime
+    pub total_deposited: u64,
+    
+    /// Lamports paid out by the admin over the protocol's lifetime
+    pub total_withdrawn: u64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl InsurancePool {
+    /// Calculate space needed for InsurancePool account
+    /// 8 (discriminator) + 8 (total deposited) + 8 (total withdrawn) + 1 (bump)
+    pub const SPACE: usize = 8 + 8 + 8 + 1;
+    
+    /// Record lamports paid into the pool
+    pub fn record_deposit(&mut self, amount: u64) -> Result<()> {
+        self.total_deposited = self
+            .total_deposited
+            .checked_add(amount)
+            .ok_or(AgentTrustError::Overflow)?;
+        Ok(())
+    }
+    
+    /// Lamports deposited and not yet withdrawn
+    pub fn available(&self) -> u64 {
+        self.total_deposited.saturating_sub(self.total_withdrawn)
+    }
+    
+    /// Record lamports paid out of the pool
+    pub fn record_withdrawal(&mut self, amount: u64) -> Result<()> {
+        require!(
+            amount <= self.available(),
+            AgentTrustError::InsufficientFunds
+        );
+        self.total_withdrawn = self
+            .total_withdrawn
+            .checked_add(amount)
+            .ok_or(AgentTrustError::Overflow)?;
+        Ok(())
+    }
+}
+
+/// Seeds for InsurancePool PDA: [b"insurance_pool"]
+pub fn get_insurance_pool_seeds() -> Vec<&'static [u8]> {
+    vec![b"insurance_pool"]
+}
@@ -1,7 +1,25 @@
 pub mod agent;
+pub mod client;
+pub mod config;
 pub mod task;
 pub mod dispute;
+pub mod dispute_queue;
+pub mod evidence;
+pub mod escrow;
+pub mod stake_vault;
+pub mod treasury;
+pub mod insurance_pool;
+pub mod arbitrator;
 
 pub use agent::*;
+pub use client::*;
+pub use config::*;
 pub use task::*;
 pub use dispute::*;
+pub use dispute_queue::*;
+pub use evidence::*;
+pub use escrow::*;
+pub use stake_vault::*;
+pub use treasury::*;
+pub use insurance_pool::*;
+pub use arbitrator::*;
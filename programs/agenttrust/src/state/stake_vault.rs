@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::utils::move_lamports;
+
+/// Holds an agent's or arbitrator's staked lamports. Everything above rent is stake,
+/// and always equals the owner's `total_stake`.
+#[account]
+pub struct StakeVault {
+    /// Agent or Arbitrator PDA this vault backs
+    pub owner: Pubkey,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl StakeVault {
+    /// Calculate space needed for StakeVault account
+    /// 8 (discriminator) + 32 (owner) + 1 (bump)
+    pub const SPACE: usize = 8 + 32 + 1;
+    
+    /// Deposit lamports from the staker's wallet
+    pub fn deposit<'info>(
+        vault: &Account<'info, StakeVault>,
+        owner: &Signer<'info>,
+        system_program: &Program<'info, System>,
+        amount: u64,
+    ) -> Result<()> {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: owner.to_account_info(),
+                    to: vault.to_account_info(),
+                },
+            ),
+            amount,
+        )
+    }
+    
+    /// Move staked lamports out of the vault (slashing or withdrawal)
+    pub fn withdraw<'info>(
+        vault: &Account<'info, StakeVault>,
+        recipient: &AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<()> {
+        move_lamports(&vault.to_account_info(), recipient, amount)
+    }
+}
+
+/// Seeds for StakeVault PDA: [b"stake_vault", agent_or_arbitrator_pubkey]
+pub fn get_stake_vault_seeds(owner: &Pubkey) -> Vec<&[u8]> {
+    vec![b"stake_vault", owner.as_ref()]
+}
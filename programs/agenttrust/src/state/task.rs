@@ -1,12 +1,17 @@
 use anchor_lang::prelude::*;
-use crate::{TaskStatus, MAX_TASK_TITLE_LEN};
+use crate::{Collateral, DisputeParty, TaskStatus, MAX_TASK_TITLE_LEN};
 use crate::errors::AgentTrustError;
+use crate::state::{Agent, ProtocolConfig, BPS_DENOMINATOR};
+use crate::utils::apply_bps;
 
 #[account]
 pub struct Task {
     /// Client who created the task
     pub client: Pubkey,
     
+    /// Sequential id from the client's profile (used in the PDA seeds)
+    pub task_id: u64,
+    
     /// Agent assigned to task (None until claimed)
     pub agent: Option<Pubkey>,
     
@@ -16,9 +21,15 @@ pub struct Task {
     /// IPFS hash of full description (32 bytes)
     pub description_hash: [u8; 32],
     
-    /// Bounty amount in lamports (locked in escrow)
+    /// SPL mint the bounty is paid in (None for lamports)
+    pub mint: Option<Pubkey>,
+    
+    /// Bounty amount in lamports or mint base units (locked in escrow)
     pub bounty: u64,
     
+    /// Protocol fee in the bounty's denomination (locked in escrow alongside the bounty)
+    pub fee: u64,
+    
     /// Deadline as Unix timestamp
     pub deadline: i64,
     
@@ -37,29 +48,91 @@ pub struct Task {
     /// Unix timestamp when deliverables submitted (None until submitted)
     pub submitted_at: Option<i64>,
     
+    /// Track record an agent needs to claim the task
+    pub requirements: ClaimRequirements,
+    
+    /// Lamports the agent locks from its stake on claiming (held in escrow until settled)
+    pub collateral: u64,
+    
     /// PDA bump seed
     pub bump: u8,
 }
 
+/// Client-set requirements an agent must meet to claim a task; zero (or None) imposes no limit
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ClaimRequirements {
+    /// Minimum reputation score (0-100)
+    pub min_reputation: u64,
+    
+    /// Minimum bonded stake in lamports (stake pending withdrawal doesn't count)
+    pub min_stake: u64,
+    
+    /// Minimum number of successfully completed tasks
+    pub min_successful_tasks: u64,
+    
+    /// Maximum number of disputes lost (None for no limit)
+    pub max_dispute_losses: Option<u64>,
+}
+
+impl ClaimRequirements {
+    /// Space taken in an account: 8 * 3 (u64 fields) + 9 (max dispute losses option)
+    pub const SPACE: usize = 24 + 9;
+    
+    /// Check an agent's record against the requirements
+    pub fn check(&self, agent: &Agent) -> Result<()> {
+        // Recomputed so a stale stored score can't get an agent past the bar
+        require!(
+            agent.calculate_reputation() >= self.min_reputation,
+            AgentTrustError::ReputationTooLow
+        );
+        
+        require!(
+            agent.bonded_stake() >= self.min_stake,
+            AgentTrustError::AgentStakeTooLow
+        );
+        
+        require!(
+            agent.successful_tasks >= self.min_successful_tasks,
+            AgentTrustError::NotEnoughSuccessfulTasks
+        );
+        
+        if let Some(max_dispute_losses) = self.max_dispute_losses {
+            require!(
+                agent.dispute_losses <= max_dispute_losses,
+                AgentTrustError::TooManyDisputeLosses
+            );
+        }
+        
+        Ok(())
+    }
+}
+
 impl Task {
     /// Calculate space needed for Task account
-    /// 8 (discriminator) + 32 (client) + 1 + 32 (agent option) + 4 + 64 (title) + 32 (desc hash) + 
-    /// 8 (bounty) + 8 (deadline) + 1 (status) + 1 + 32 (deliverable option) + 8 (created) + 
-    /// 1 + 8 (claimed option) + 1 + 8 (submitted option) + 1 (bump)
-    pub const SPACE: usize = 8 + 32 + 33 + 4 + MAX_TASK_TITLE_LEN + 32 + 8 + 8 + 1 + 33 + 8 + 9 + 9 + 1;
+    /// 8 (discriminator) + 32 (client) + 8 (task_id) + 1 + 32 (agent option) + 4 + 64 (title) + 32 (desc hash) + 
+    /// 33 (mint option) + 8 (bounty) + 8 (fee) + 8 (deadline) + 1 (status) + 1 + 32 (deliverable option) + 8 (created) + 
+    /// 1 + 8 (claimed option) + 1 + 8 (submitted option) + ClaimRequirements::SPACE + 8 (collateral) + 1 (bump)
+    pub const SPACE: usize = 8 + 32 + 8 + 33 + 4 + MAX_TASK_TITLE_LEN + 32 + 33 + 8 + 8 + 8 + 1 + 33 + 8 + 9 + 9 +
+        ClaimRequirements::SPACE + 8 + 1;
     
-    /// Create a new task
+    /// Create a new task; `fee` is the client's protocol fee from `ProtocolConfig::calculate_fee`
+    #[allow(clippy::too_many_arguments)]
     pub fn create(
         &mut self,
         client: Pubkey,
+        task_id: u64,
         title: String,
         description_hash: [u8; 32],
         bounty: u64,
+        fee: u64,
         deadline: i64,
+        requirements: ClaimRequirements,
+        collateral: Collateral,
+        config: &ProtocolConfig,
         bump: u8,
     ) -> Result<()> {
         require!(
-            title.len() <= MAX_TASK_TITLE_LEN,
+            title.len() <= config.max_task_title_len as usize,
             AgentTrustError::TitleTooLong
         );
         
@@ -73,24 +146,40 @@ impl Task {
             AgentTrustError::InvalidDeadline
         );
         
+        if let Collateral::BountyBps { bps } = collateral {
+            require!(
+                bps as u64 <= BPS_DENOMINATOR,
+                AgentTrustError::CollateralExceedsBounty
+            );
+        }
+        
         self.client = client;
+        self.task_id = task_id;
         self.agent = None;
         self.title = title;
         self.description_hash = description_hash;
+        self.mint = None;
         self.bounty = bounty;
+        self.fee = fee;
         self.deadline = deadline;
         self.status = TaskStatus::Open;
         self.deliverable_hash = None;
         self.created_at = Clock::get()?.unix_timestamp;
         self.claimed_at = None;
         self.submitted_at = None;
+        self.requirements = requirements;
+        self.collateral = match collateral {
+            Collateral::None => 0,
+            Collateral::Fixed { lamports } => lamports,
+            Collateral::BountyBps { bps } => apply_bps(bounty, bps as u64),
+        };
         self.bump = bump;
         
         Ok(())
     }
     
-    /// Claim the task
-    pub fn claim(&mut self, agent: Pubkey) -> Result<()> {
+    /// Claim the task for an agent meeting the client's requirements
+    pub fn claim(&mut self, agent: &Agent) -> Result<()> {
         require!(
             self.status == TaskStatus::Open,
             AgentTrustError::TaskNotOpen
@@ -101,7 +190,9 @@ impl Task {
             AgentTrustError::DeadlinePassed
         );
         
-        self.agent = Some(agent);
+        self.requirements.check(agent)?;
+        
+        self.agent = Some(agent.owner);
         self.status = TaskStatus::Claimed;
         self.claimed_at = Some(Clock::get()?.unix_timestamp);
         
@@ -115,6 +206,11 @@ impl Task {
             AgentTrustError::TaskNotClaimed
         );
         
+        require!(
+            !self.is_deadline_passed(),
+            AgentTrustError::DeadlinePassed
+        );
+        
         self.deliverable_hash = Some(deliverable_hash);
         self.status = TaskStatus::Submitted;
         self.submitted_at = Some(Clock::get()?.unix_timestamp);
@@ -134,18 +230,82 @@ impl Task {
         Ok(())
     }
     
-    /// Dispute the task
-    pub fn dispute(&mut self) -> Result<()> {
+    /// Dispute the task. Clients dispute a submission; agents can also dispute
+    /// while the task is claimed (e.g. when the client changed the requirements).
+    pub fn dispute(&mut self, initiator: Pubkey) -> Result<DisputeParty> {
+        let party = if initiator == self.client {
+            require!(
+                self.status == TaskStatus::Submitted,
+                AgentTrustError::TaskNotSubmitted
+            );
+            DisputeParty::Client
+        } else if self.agent == Some(initiator) {
+            require!(
+                self.status == TaskStatus::Submitted || self.status == TaskStatus::Claimed,
+                AgentTrustError::TaskNotDisputable
+            );
+            DisputeParty::Agent
+        } else {
+            return err!(AgentTrustError::Unauthorized);
+        };
+        
+        self.status = TaskStatus::Disputed;
+        
+        Ok(party)
+    }
+    
+    /// Settle a disputed task once its dispute is resolved
+    pub fn resolve(&mut self) -> Result<()> {
+        require!(
+            self.status == TaskStatus::Disputed,
+            AgentTrustError::TaskNotDisputed
+        );
+        
+        self.status = TaskStatus::Resolved;
+        
+        Ok(())
+    }
+    
+    /// Cancel an unclaimed task
+    pub fn cancel(&mut self) -> Result<()> {
         require!(
-            self.status == TaskStatus::Submitted,
-            AgentTrustError::TaskNotSubmitted
+            self.status == TaskStatus::Open,
+            AgentTrustError::TaskNotOpen
         );
         
-        self.status = TaskStatus::Disputed;
+        self.status = TaskStatus::Cancelled;
         
         Ok(())
     }
     
+    /// Expire a claimed task whose deadline passed without a delivery
+    pub fn expire(&mut self) -> Result<()> {
+        require!(
+            self.status == TaskStatus::Claimed,
+            AgentTrustError::TaskNotClaimed
+        );
+        
+        require!(
+            self.is_deadline_passed(),
+            AgentTrustError::DeadlineNotPassed
+        );
+        
+        self.status = TaskStatus::Expired;
+        
+        Ok(())
+    }
+    
+    /// Check if the client's review window after submission has lapsed
+    pub fn is_review_window_over(&self, review_window_seconds: i64) -> bool {
+        let current_time = Clock::get()
+            .map(|c| c.unix_timestamp)
+            .unwrap_or(0);
+        
+        self.submitted_at
+            .map(|submitted_at| current_time > submitted_at.saturating_add(review_window_seconds))
+            .unwrap_or(false)
+    }
+    
     /// Check if deadline has passed
     pub fn is_deadline_passed(&self) -> bool {
         Clock::get()
@@ -154,10 +314,13 @@ impl Task {
     }
 }
 
-/// Seeds for Task PDA: [b"task", client_pubkey, task_id_counter]
-pub fn get_task_seeds(client: &Pubkey, task_id: u64) -> Vec<u8> {
-    let mut seeds = b"task".to_vec();
-    seeds.extend_from_slice(client.as_ref());
-    seeds.extend_from_slice(&task_id.to_le_bytes());
-    seeds
+/// Seeds for Task PDA: [b"task", client_pubkey, task_id (u64 little-endian)]
+pub fn get_task_seeds<'a>(client: &'a Pubkey, task_id: &'a [u8; 8]) -> Vec<&'a [u8]> {
+    vec![b"task", client.as_ref(), task_id]
+}
+
+/// Derive the address of a client's task from its sequential id
+pub fn find_task_address(client: &Pubkey, task_id: u64) -> (Pubkey, u8) {
+    let task_id = task_id.to_le_bytes();
+    Pubkey::find_program_address(&get_task_seeds(client, &task_id), &crate::ID)
 }
@@ -0,0 +1,186 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::errors::AgentTrustError;
+use crate::state::{Escrow, EscrowVault};
+
+#[account]
+pub struct Treasury {
+    /// Lamport fees collected over the protocol's lifetime
+    pub total_lamport_fees: u64,
+    
+    /// Lamports slashed from agents and arbitrators into the treasury (kept apart from fees)
+    pub total_slashed_lamports: u64,
+    
+    /// Lamport fees withdrawn by the admin
+    pub total_lamport_withdrawn: u64,
+    
+    /// Lamports paid out to arbitrators when a forfeited filing bond can't cover their fee
+    pub total_arbitration_paid: u64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// Fee accounting for one SPL mint
+#[account]
+pub struct FeeLedger {
+    /// Mint the fees are denominated in
+    pub mint: Pubkey,
+    
+    /// Treasury-owned token account holding the fees
+    pub vault: Pubkey,
+    
+    /// Token fees collected over the protocol's lifetime (net of transfer fees)
+    pub total_fees: u64,
+    
+    /// Token fees withdrawn by the admin
+    pub total_withdrawn: u64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Treasury {
+    /// Calculate space needed for Treasury account
+    /// 8 (discriminator) + 8 (total fees) + 8 (total slashed) + 8 (total withdrawn) +
+    /// 8 (arbitration paid) + 1 (bump)
+    pub const SPACE: usize = 8 + 8 + 8 + 8 + 8 + 1;
+    
+    /// Lamports available to withdraw: collected fees plus slashed stake, less what
+    /// has been withdrawn or paid to arbitrators
+    pub fn available_lamports(&self) -> u64 {
+        self.total_lamport_fees
+            .saturating_add(self.total_slashed_lamports)
+            .saturating_sub(self.total_lamport_withdrawn)
+            .saturating_sub(self.total_arbitration_paid)
+    }
+    
+    /// Record a lamport withdrawal
+    pub fn record_withdrawal(&mut self, amount: u64) -> Result<()> {
+        require!(
+            amount <= self.available_lamports(),
+            AgentTrustError::InsufficientFunds
+        );
+        self.total_lamport_withdrawn = self
+            .total_lamport_withdrawn
+            .checked_add(amount)
+            .ok_or(AgentTrustError::Overflow)?;
+        Ok(())
+    }
+    
+    /// Record lamports paid toward an arbitration fee
+    pub fn record_arbitration_payment(&mut self, amount: u64) -> Result<()> {
+        require!(
+            amount <= self.available_lamports(),
+            AgentTrustError::InsufficientFunds
+        );
+        self.total_arbitration_paid = self
+            .total_arbitration_paid
+            .checked_add(amount)
+            .ok_or(AgentTrustError::Overflow)?;
+        Ok(())
+    }
+    
+    /// Record stake slashed into the treasury
+    pub fn record_slash(&mut self, amount: u64) -> Result<()> {
+        self.total_slashed_lamports = self
+            .total_slashed_lamports
+            .checked_add(amount)
+            .ok_or(AgentTrustError::Overflow)?;
+        Ok(())
+    }
+    
+    /// Move the protocol fee out of a task escrow into the treasury and record it.
+    /// Token fees go to the mint's fee vault and are recorded on its ledger.
+    pub fn collect_fee<'info>(
+        treasury: &mut Account<'info, Treasury>,
+        escrow: &mut Account<'info, Escrow>,
+        vault: Option<&EscrowVault<'_, 'info>>,
+        fee_vault: Option<&mut InterfaceAccount<'info, TokenAccount>>,
+        fee_ledger: Option<&mut Account<'info, FeeLedger>>,
+        amount: u64,
+    ) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        
+        match escrow.mint {
+            None => {
+                Escrow::release(escrow, &treasury.to_account_info(), amount)?;
+                treasury.total_lamport_fees = treasury
+                    .total_lamport_fees
+                    .checked_add(amount)
+                    .ok_or(AgentTrustError::Overflow)?;
+            }
+            Some(mint) => {
+                let vault = vault.ok_or(AgentTrustError::MissingTokenAccounts)?;
+                let fee_vault = fee_vault.ok_or(AgentTrustError::MissingTokenAccounts)?;
+                let fee_ledger = fee_ledger.ok_or(AgentTrustError::MissingTokenAccounts)?;
+                
+                require!(
+                    fee_ledger.mint == mint && fee_ledger.vault == fee_vault.key(),
+                    AgentTrustError::InvalidMint
+                );
+                
+                // Record what actually arrived, after any Token-2022 transfer fee
+                let before = fee_vault.amount;
+                Escrow::release_tokens(escrow, vault, fee_vault, amount)?;
+                fee_vault.reload()?;
+                let received = fee_vault.amount.saturating_sub(before);
+                
+                fee_ledger.total_fees = fee_ledger
+                    .total_fees
+                    .checked_add(received)
+                    .ok_or(AgentTrustError::Overflow)?;
+            }
+        }
+        
+        Ok(())
+    }
+}
+
+impl FeeLedger {
+    /// Calculate space needed for FeeLedger account
+    /// 8 (discriminator) + 32 (mint) + 32 (vault) + 8 (total fees) + 8 (total withdrawn) + 1 (bump)
+    pub const SPACE: usize = 8 + 32 + 32 + 8 + 8 + 1;
+    
+    /// Initialize the ledger on first use of a mint
+    pub fn init_if_new(&mut self, mint: Pubkey, vault: Pubkey, bump: u8) {
+        if self.mint == Pubkey::default() {
+            self.mint = mint;
+            self.vault = vault;
+            self.total_fees = 0;
+            self.total_withdrawn = 0;
+            self.bump = bump;
+        }
+    }
+    
+    /// Token fees available to withdraw
+    pub fn available(&self) -> u64 {
+        self.total_fees.saturating_sub(self.total_withdrawn)
+    }
+    
+    /// Record a token withdrawal
+    pub fn record_withdrawal(&mut self, amount: u64) -> Result<()> {
+        require!(
+            amount <= self.available(),
+            AgentTrustError::InsufficientFunds
+        );
+        self.total_withdrawn = self
+            .total_withdrawn
+            .checked_add(amount)
+            .ok_or(AgentTrustError::Overflow)?;
+        Ok(())
+    }
+}
+
+/// Seeds for Treasury PDA: [b"treasury"]
+pub fn get_treasury_seeds() -> Vec<&'static [u8]> {
+    vec![b"treasury"]
+}
+
+/// Seeds for FeeLedger PDA: [b"fee_ledger", mint_pubkey]
+/// The ledger's token vault is seeded [b"treasury_vault", mint_pubkey]
+pub fn get_fee_ledger_seeds(mint: &Pubkey) -> Vec<&[u8]> {
+    vec![b"fee_ledger", mint.as_ref()]
+}
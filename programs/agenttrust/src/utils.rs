@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::errors::AgentTrustError;
+use crate::state::BPS_DENOMINATOR;
+
+/// Move lamports out of a program-owned account.
+/// Program-owned PDAs can't use the system program to send lamports,
+/// so balances are adjusted directly.
+pub fn move_lamports<'info>(
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    **from.try_borrow_mut_lamports()? = from
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(AgentTrustError::InsufficientFunds)?;
+    **to.try_borrow_mut_lamports()? = to
+        .lamports()
+        .checked_add(amount)
+        .ok_or(AgentTrustError::Overflow)?;
+    
+    Ok(())
+}
+
+/// Share of `amount` given in basis points, rounded down
+pub fn apply_bps(amount: u64, bps: u64) -> u64 {
+    ((amount as u128 * bps as u128) / BPS_DENOMINATOR as u128) as u64
+}
+
+/// Hash of the first slot after `slot`, read from the SlotHashes sysvar.
+/// Returns None once `slot` has dropped out of the sysvar's window, since the
+/// first later slot can no longer be told apart from a skipped one.
+pub fn slot_hash_after(slot_hashes: &AccountInfo, slot: u64) -> Result<Option<[u8; 32]>> {
+    const ENTRY_LEN: usize = 8 + 32;
+    
+    let data = slot_hashes.try_borrow_data()?;
+    require!(
+        data.len() >= 8,
+        AgentTrustError::PanelSeatingNotReady
+    );
+    
+    let mut len = [0u8; 8];
+    len.copy_from_slice(&data[..8]);
+    let entries = data[8..]
+        .chunks_exact(ENTRY_LEN)
+        .take(u64::from_le_bytes(len) as usize);
+    
+    // Entries run newest first, so the last one after `slot` is the next slot
+    let mut next = None;
+    for entry in entries {
+        let mut entry_slot = [0u8; 8];
+        entry_slot.copy_from_slice(&entry[..8]);
+        if u64::from_le_bytes(entry_slot) <= slot {
+            return match next {
+                Some(hash) => Ok(Some(hash)),
+                None => err!(AgentTrustError::PanelSeatingNotReady),
+            };
+        }
+        
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(&entry[8..]);
+        next = Some(hash);
+    }
+    
+    Ok(None)
+}
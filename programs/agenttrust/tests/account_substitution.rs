@@ -0,0 +1,307 @@
+//! Settlement instructions must reject accounts that belong to a different task.
+//!
+//! Each test builds the accounts for two unrelated tasks, swaps one of task B's
+//! accounts into task A's instruction and runs Anchor's account validation.
+
+mod common;
+
+use std::collections::BTreeSet;
+
+use agenttrust::errors::AgentTrustError;
+use agenttrust::instructions::{
+    ConfirmTask, ConfirmTaskBumps, ResolveDispute, ResolveDisputeBumps,
+};
+use agenttrust::state::Dispute;
+use agenttrust::{DisputeStatus, TaskStatus, ID};
+use anchor_lang::error::ErrorCode;
+use anchor_lang::prelude::*;
+use common::{
+    arbitrator_registry, blank, config, infos, install_clock, insurance_pool, pda,
+    treasury, Fixture, TestAccount,
+};
+
+/// Account indices within `confirm_accounts`
+mod confirm {
+    pub const CLIENT: usize = 0;
+    pub const AGENT_ACCOUNT: usize = 2;
+    pub const STAKE_VAULT: usize = 3;
+    pub const AGENT: usize = 4;
+    pub const ESCROW: usize = 5;
+}
+
+fn confirm_accounts(fixture: &Fixture) -> Vec<TestAccount> {
+    vec![
+        fixture.client(true),
+        fixture.task(TaskStatus::Submitted),
+        fixture.agent_account(),
+        fixture.stake_vault(),
+        fixture.agent(),
+        fixture.escrow(),
+        treasury(),
+        TestAccount::none(), // mint
+        TestAccount::none(), // escrow_vault
+        TestAccount::none(), // agent_token_account
+        TestAccount::none(), // fee_ledger
+        TestAccount::none(), // fee_vault
+        TestAccount::none(), // token_program
+        TestAccount::system_program(),
+    ]
+}
+
+/// Account indices within `resolve_accounts`
+mod resolve {
+    pub const DISPUTE: usize = 2;
+    pub const TASK: usize = 3;
+    pub const AGENT_ACCOUNT: usize = 4;
+    pub const STAKE_VAULT: usize = 5;
+    pub const CLIENT_PROFILE: usize = 6;
+    pub const CLIENT: usize = 7;
+    pub const AGENT: usize = 8;
+    pub const ESCROW: usize = 9;
+}
+
+fn resolve_accounts(fixture: &Fixture) -> Vec<TestAccount> {
+    vec![
+        TestAccount::wallet(Pubkey::new_unique(), true), // cranker
+        config(),
+        fixture.dispute(),
+        fixture.task(TaskStatus::Disputed),
+        fixture.agent_account(),
+        fixture.stake_vault(),
+        fixture.client_profile(),
+        fixture.client(false),
+        fixture.agent(),
+        fixture.escrow(),
+        treasury(),
+        insurance_pool(),
+        arbitrator_registry(),
+        TestAccount::none(), // mint
+        TestAccount::none(), // escrow_vault
+        TestAccount::none(), // client_token_account
+        TestAccount::none(), // agent_token_account
+        TestAccount::none(), // fee_ledger
+        TestAccount::none(), // fee_vault
+        TestAccount::none(), // token_program
+        TestAccount::system_program(),
+    ]
+}
+
+fn validate_confirm(accounts: Vec<TestAccount>) -> Result<()> {
+    let mut infos = infos(accounts);
+    ConfirmTask::try_accounts(
+        &ID,
+        &mut infos,
+        &[],
+        &mut ConfirmTaskBumps::default(),
+        &mut BTreeSet::new(),
+    )
+    .map(|_| ())
+}
+
+fn validate_resolve(accounts: Vec<TestAccount>) -> Result<()> {
+    install_clock();
+    let mut infos = infos(accounts);
+    ResolveDispute::try_accounts(
+        &ID,
+        &mut infos,
+        &[],
+        &mut ResolveDisputeBumps::default(),
+        &mut BTreeSet::new(),
+    )
+    .map(|_| ())
+}
+
+fn error_code(result: Result<()>) -> u32 {
+    match result {
+        Err(Error::AnchorError(error)) => error.error_code_number,
+        Err(Error::ProgramError(error)) => panic!("unexpected program error: {:?}", error),
+        Ok(()) => panic!("substituted account was accepted"),
+    }
+}
+
+fn seeds_error() -> u32 {
+    ErrorCode::ConstraintSeeds.into()
+}
+
+#[test]
+fn confirm_accepts_the_task_accounts() {
+    let a = Fixture::new();
+
+    validate_confirm(confirm_accounts(&a)).unwrap();
+}
+
+#[test]
+fn confirm_rejects_another_agents_account() {
+    let a = Fixture::new();
+    let b = Fixture::new();
+    let mut accounts = confirm_accounts(&a);
+    accounts[confirm::AGENT_ACCOUNT] = b.agent_account();
+
+    assert_eq!(error_code(validate_confirm(accounts)), seeds_error());
+}
+
+#[test]
+fn confirm_rejects_another_agents_stake_vault() {
+    let a = Fixture::new();
+    let b = Fixture::new();
+    let mut accounts = confirm_accounts(&a);
+    accounts[confirm::STAKE_VAULT] = b.stake_vault();
+
+    assert_eq!(error_code(validate_confirm(accounts)), seeds_error());
+}
+
+#[test]
+fn confirm_rejects_another_tasks_escrow() {
+    let a = Fixture::new();
+    let b = Fixture::new();
+    let mut accounts = confirm_accounts(&a);
+    accounts[confirm::ESCROW] = b.escrow();
+
+    assert_eq!(error_code(validate_confirm(accounts)), seeds_error());
+}
+
+#[test]
+fn confirm_rejects_another_tasks_client() {
+    let a = Fixture::new();
+    let b = Fixture::new();
+    let mut accounts = confirm_accounts(&a);
+    accounts[confirm::CLIENT] = b.client(true);
+
+    assert_eq!(
+        error_code(validate_confirm(accounts)),
+        u32::from(AgentTrustError::NotClient)
+    );
+}
+
+#[test]
+fn confirm_rejects_another_tasks_agent_accounts() {
+    let a = Fixture::new();
+    let b = Fixture::new();
+    let mut accounts = confirm_accounts(&a);
+
+    // Task B's agent wallet and PDA are consistent with each other but not with task A
+    accounts[confirm::AGENT_ACCOUNT] = b.agent_account();
+    accounts[confirm::AGENT] = b.agent();
+
+    assert_eq!(error_code(validate_confirm(accounts)), seeds_error());
+}
+
+#[test]
+fn resolve_accepts_the_task_accounts() {
+    let a = Fixture::new();
+
+    validate_resolve(resolve_accounts(&a)).unwrap();
+}
+
+#[test]
+fn resolve_rejects_another_tasks_dispute() {
+    let a = Fixture::new();
+    let b = Fixture::new();
+    let mut accounts = resolve_accounts(&a);
+    accounts[resolve::DISPUTE] = b.dispute();
+
+    assert_eq!(error_code(validate_resolve(accounts)), seeds_error());
+}
+
+#[test]
+fn resolve_rejects_a_dispute_recorded_for_another_task() {
+    let a = Fixture::new();
+    let b = Fixture::new();
+    let mut accounts = resolve_accounts(&a);
+
+    // Task A's dispute address holding a dispute over task B
+    let (key, bump) = pda(&[b"dispute", a.task.as_ref()]);
+    let mut dispute = blank::<Dispute>();
+    dispute.task = b.task;
+    dispute.status = DisputeStatus::Resolved;
+    dispute.bump = bump;
+    accounts[resolve::DISPUTE] = TestAccount::program(key, &dispute);
+
+    assert_eq!(
+        error_code(validate_resolve(accounts)),
+        u32::from(AgentTrustError::TaskAccountMismatch)
+    );
+}
+
+#[test]
+fn resolve_rejects_another_tasks_dispute_and_task() {
+    let a = Fixture::new();
+    let b = Fixture::new();
+    let mut accounts = resolve_accounts(&a);
+    accounts[resolve::DISPUTE] = b.dispute();
+    accounts[resolve::TASK] = b.task(TaskStatus::Disputed);
+
+    // The remaining accounts still belong to task A
+    assert_eq!(error_code(validate_resolve(accounts)), seeds_error());
+}
+
+#[test]
+fn resolve_rejects_another_agents_account() {
+    let a = Fixture::new();
+    let b = Fixture::new();
+    let mut accounts = resolve_accounts(&a);
+    accounts[resolve::AGENT_ACCOUNT] = b.agent_account();
+
+    assert_eq!(error_code(validate_resolve(accounts)), seeds_error());
+}
+
+#[test]
+fn resolve_rejects_another_agents_account_and_stake_vault() {
+    let a = Fixture::new();
+    let b = Fixture::new();
+    let mut accounts = resolve_accounts(&a);
+    accounts[resolve::AGENT_ACCOUNT] = b.agent_account();
+    accounts[resolve::STAKE_VAULT] = b.stake_vault();
+
+    assert_eq!(error_code(validate_resolve(accounts)), seeds_error());
+}
+
+#[test]
+fn resolve_rejects_another_agents_stake_vault() {
+    let a = Fixture::new();
+    let b = Fixture::new();
+    let mut accounts = resolve_accounts(&a);
+    accounts[resolve::STAKE_VAULT] = b.stake_vault();
+
+    assert_eq!(error_code(validate_resolve(accounts)), seeds_error());
+}
+
+#[test]
+fn resolve_rejects_another_tasks_escrow() {
+    let a = Fixture::new();
+    let b = Fixture::new();
+    let mut accounts = resolve_accounts(&a);
+    accounts[resolve::ESCROW] = b.escrow();
+
+    assert_eq!(error_code(validate_resolve(accounts)), seeds_error());
+}
+
+#[test]
+fn resolve_rejects_another_clients_profile() {
+    let a = Fixture::new();
+    let b = Fixture::new();
+    let mut accounts = resolve_accounts(&a);
+    accounts[resolve::CLIENT_PROFILE] = b.client_profile();
+
+    assert_eq!(error_code(validate_resolve(accounts)), seeds_error());
+}
+
+#[test]
+fn resolve_rejects_another_tasks_wallets() {
+    let a = Fixture::new();
+    let b = Fixture::new();
+
+    let mut accounts = resolve_accounts(&a);
+    accounts[resolve::CLIENT] = b.client(false);
+    assert_eq!(
+        error_code(validate_resolve(accounts)),
+        u32::from(AgentTrustError::NotClient)
+    );
+
+    let mut accounts = resolve_accounts(&a);
+    accounts[resolve::AGENT] = b.agent();
+    assert_eq!(
+        error_code(validate_resolve(accounts)),
+        u32::from(AgentTrustError::NotAssignedAgent)
+    );
+}
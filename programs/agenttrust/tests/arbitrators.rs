@@ -0,0 +1,119 @@
+//! Arbitrator records and standing: how votes are counted, and how the admin takes
+//! an arbitrator off panels and lets it back on.
+
+mod common;
+
+use agenttrust::errors::AgentTrustError;
+use agenttrust::instructions::{reinstate_arbitrator, ReinstateArbitrator};
+use agenttrust::state::{Arbitrator, ArbitratorRegistry};
+use agenttrust::ArbitratorStatus;
+use anchor_lang::prelude::*;
+use common::{
+    arbitrator_registry, blank, infos, install_clock, pda, protocol_config, read, run, TestAccount,
+    SOL,
+};
+
+/// A registered arbitrator staked at the default minimum
+fn arbitrator(authority: Pubkey) -> Arbitrator {
+    install_clock();
+    let mut arbitrator = blank::<Arbitrator>();
+    arbitrator
+        .register(authority, pda(&[b"arbitrator", authority.as_ref()]).1)
+        .unwrap();
+    arbitrator.total_stake = protocol_config().min_arbitrator_stake_lamports;
+    arbitrator
+}
+
+/// Accounts for reinstate_arbitrator, signed by the protocol admin
+fn reinstate_accounts(arbitrator: &Arbitrator) -> &'static [AccountInfo<'static>] {
+    let admin = Pubkey::new_unique();
+    let (config_key, config_bump) = pda(&[b"config"]);
+    let mut config = protocol_config();
+    config.admin = admin;
+    config.bump = config_bump;
+    let (arbitrator_key, _) = pda(&[b"arbitrator", arbitrator.authority.as_ref()]);
+
+    infos(vec![
+        TestAccount::wallet(admin, true),
+        TestAccount::program(config_key, &config),
+        TestAccount::program(arbitrator_key, arbitrator),
+        arbitrator_registry(),
+    ])
+}
+
+#[test]
+fn rerun_rounds_of_a_dispute_count_as_one_vote() {
+    let mut arbitrator = arbitrator(Pubkey::new_unique());
+
+    arbitrator.record_vote(0, 10).unwrap();
+    arbitrator.record_vote(1, 30).unwrap();
+    arbitrator.record_vote(2, 50).unwrap();
+
+    assert_eq!(arbitrator.votes_cast, 1);
+    assert_eq!(arbitrator.total_vote_latency, 10);
+}
+
+#[test]
+fn each_dispute_counts_as_a_vote() {
+    let mut arbitrator = arbitrator(Pubkey::new_unique());
+
+    arbitrator.record_vote(0, 10).unwrap();
+    arbitrator.record_vote(0, 20).unwrap();
+
+    assert_eq!(arbitrator.votes_cast, 2);
+    assert_eq!(arbitrator.total_vote_latency, 30);
+}
+
+#[test]
+fn a_suspended_arbitrator_can_be_reinstated_onto_panels() {
+    let mut arbitrator = arbitrator(Pubkey::new_unique());
+    arbitrator.record_missed_deadline().unwrap();
+    let accounts = reinstate_accounts(&arbitrator);
+
+    run::<ReinstateArbitrator>(accounts, reinstate_arbitrator::handler).unwrap();
+
+    let reinstated: Arbitrator = read(&accounts[2]);
+    let registry: ArbitratorRegistry = read(&accounts[3]);
+    assert!(reinstated.status == ArbitratorStatus::Active);
+    assert_eq!(reinstated.missed_deadlines, 1);
+    assert_eq!(registry.arbitrators, vec![arbitrator.authority]);
+}
+
+#[test]
+fn a_reinstated_arbitrator_below_the_minimum_stake_stays_off_panels() {
+    let mut arbitrator = arbitrator(Pubkey::new_unique());
+    arbitrator.total_stake = SOL / 100;
+    arbitrator.suspend().unwrap();
+    let accounts = reinstate_accounts(&arbitrator);
+
+    run::<ReinstateArbitrator>(accounts, reinstate_arbitrator::handler).unwrap();
+
+    let reinstated: Arbitrator = read(&accounts[2]);
+    let registry: ArbitratorRegistry = read(&accounts[3]);
+    assert!(reinstated.is_active());
+    assert!(registry.arbitrators.is_empty());
+}
+
+#[test]
+fn only_a_suspended_arbitrator_can_be_reinstated() {
+    let accounts = reinstate_accounts(&arbitrator(Pubkey::new_unique()));
+
+    assert_eq!(
+        run::<ReinstateArbitrator>(accounts, reinstate_arbitrator::handler).unwrap_err(),
+        AgentTrustError::ArbitratorNotSuspended.into()
+    );
+}
+
+#[test]
+fn only_the_admin_can_reinstate_an_arbitrator() {
+    let mut arbitrator = arbitrator(Pubkey::new_unique());
+    arbitrator.suspend().unwrap();
+    let mut accounts = reinstate_accounts(&arbitrator).to_vec();
+    let impostor = infos(vec![TestAccount::wallet(Pubkey::new_unique(), true)]);
+    accounts[0] = impostor[0].clone();
+
+    assert_eq!(
+        run::<ReinstateArbitrator>(accounts.leak(), reinstate_arbitrator::handler).unwrap_err(),
+        AgentTrustError::Unauthorized.into()
+    );
+}
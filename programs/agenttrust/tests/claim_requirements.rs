@@ -0,0 +1,143 @@
+//! Client requirements an agent's record must meet before it can claim a task.
+//!
+//! Each test checks one requirement against an agent just inside and just outside
+//! the bar, with every other requirement left at "no limit".
+
+mod common;
+
+use agenttrust::errors::AgentTrustError;
+use agenttrust::state::{Agent, ClaimRequirements};
+use common::{blank, SOL};
+
+/// An agent with ten successful tasks and 50 SOL bonded: a reputation of 80
+fn agent() -> Agent {
+    let mut agent = blank::<Agent>();
+    agent.successful_tasks = 10;
+    agent.total_tasks = 10;
+    agent.total_stake = 50 * SOL;
+    agent
+}
+
+fn requirements(edit: impl FnOnce(&mut ClaimRequirements)) -> ClaimRequirements {
+    let mut requirements = ClaimRequirements::default();
+    edit(&mut requirements);
+    requirements
+}
+
+#[test]
+fn no_requirements_accept_a_new_agent() {
+    ClaimRequirements::default()
+        .check(&blank::<Agent>())
+        .unwrap();
+}
+
+#[test]
+fn reputation_must_reach_the_minimum() {
+    requirements(|r| r.min_reputation = 80)
+        .check(&agent())
+        .unwrap();
+
+    assert_eq!(
+        requirements(|r| r.min_reputation = 81)
+            .check(&agent())
+            .unwrap_err(),
+        AgentTrustError::ReputationTooLow.into()
+    );
+}
+
+#[test]
+fn a_stale_stored_score_does_not_meet_the_reputation_bar() {
+    let mut agent = agent();
+    agent.reputation_score = 100;
+
+    assert_eq!(
+        requirements(|r| r.min_reputation = 90)
+            .check(&agent)
+            .unwrap_err(),
+        AgentTrustError::ReputationTooLow.into()
+    );
+}
+
+#[test]
+fn bonded_stake_must_reach_the_minimum() {
+    requirements(|r| r.min_stake = 50 * SOL)
+        .check(&agent())
+        .unwrap();
+
+    assert_eq!(
+        requirements(|r| r.min_stake = 50 * SOL + 1)
+            .check(&agent())
+            .unwrap_err(),
+        AgentTrustError::AgentStakeTooLow.into()
+    );
+}
+
+#[test]
+fn unbonding_stake_does_not_count_toward_the_minimum() {
+    let mut agent = agent();
+    agent.pending_unstake = 1;
+
+    assert_eq!(
+        requirements(|r| r.min_stake = 50 * SOL)
+            .check(&agent)
+            .unwrap_err(),
+        AgentTrustError::AgentStakeTooLow.into()
+    );
+}
+
+#[test]
+fn collateral_locked_in_other_tasks_counts_toward_the_minimum() {
+    let mut agent = agent();
+    agent.total_stake = 40 * SOL;
+    agent.locked_collateral = 10 * SOL;
+
+    requirements(|r| r.min_stake = 50 * SOL)
+        .check(&agent)
+        .unwrap();
+}
+
+#[test]
+fn successful_tasks_must_reach_the_minimum() {
+    requirements(|r| r.min_successful_tasks = 10)
+        .check(&agent())
+        .unwrap();
+
+    assert_eq!(
+        requirements(|r| r.min_successful_tasks = 11)
+            .check(&agent())
+            .unwrap_err(),
+        AgentTrustError::NotEnoughSuccessfulTasks.into()
+    );
+}
+
+#[test]
+fn dispute_losses_must_not_exceed_the_maximum() {
+    let mut agent = agent();
+    agent.dispute_losses = 2;
+
+    requirements(|r| r.max_dispute_losses = Some(2))
+        .check(&agent)
+        .unwrap();
+
+    assert_eq!(
+        requirements(|r| r.max_dispute_losses = Some(1))
+            .check(&agent)
+            .unwrap_err(),
+        AgentTrustError::TooManyDisputeLosses.into()
+    );
+}
+
+#[test]
+fn no_dispute_loss_limit_accepts_any_record() {
+    let mut agent = agent();
+    agent.dispute_losses = 5;
+
+    ClaimRequirements::default().check(&agent).unwrap();
+}
+
+#[test]
+fn a_clean_record_passes_zero_tolerance_for_dispute_losses() {
+    requirements(|r| r.max_dispute_losses = Some(0))
+        .check(&agent())
+        .unwrap();
+}
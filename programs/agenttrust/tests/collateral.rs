@@ -0,0 +1,235 @@
+//! Agent collateral over a task's life: locked from stake at claim, returned on
+//! confirmation and slashed when the task expires.
+//!
+//! Each test runs an instruction's handler against in-memory accounts and checks
+//! the stake, escrow and lamport balances it leaves behind.
+
+mod common;
+
+use agenttrust::errors::AgentTrustError;
+use agenttrust::instructions::{
+    auto_confirm_task, claim_task, confirm_task, expire_task, AutoConfirmTask, ClaimTask,
+    ConfirmTask, ExpireTask,
+};
+use agenttrust::state::{Agent, Escrow, InsurancePool, Task, Treasury};
+use agenttrust::{Collateral, TaskStatus};
+use anchor_lang::prelude::*;
+use common::{
+    blank, config, infos, install_clock, insurance_pool, protocol_config, read, run, treasury,
+    Fixture, TestAccount, NOW, SOL,
+};
+
+const BOUNTY: u64 = SOL;
+const FEE: u64 = SOL / 100;
+const COLLATERAL: u64 = SOL / 2;
+
+/// An agent with `stake` lamports free and the task's collateral locked
+fn agent_with_locked_collateral(fixture: &Fixture, stake: u64) -> TestAccount {
+    fixture.agent_account_with(|agent| {
+        agent.total_stake = stake;
+        agent.locked_collateral = COLLATERAL;
+        agent.active_tasks = 1;
+    })
+}
+
+/// The escrow of a claimed task, holding the bounty, fee and collateral
+fn funded_escrow(fixture: &Fixture) -> TestAccount {
+    fixture
+        .escrow_with(|escrow| {
+            escrow.balance = BOUNTY + FEE;
+            escrow.collateral = COLLATERAL;
+        })
+        .with_lamports(SOL + BOUNTY + FEE + COLLATERAL)
+}
+
+fn submitted_task(fixture: &Fixture, submitted_at: i64) -> TestAccount {
+    fixture.task_with(TaskStatus::Submitted, |task| {
+        task.bounty = BOUNTY;
+        task.fee = FEE;
+        task.collateral = COLLATERAL;
+        task.submitted_at = Some(submitted_at);
+    })
+}
+
+/// Accounts of the payout shared by confirm_task and auto_confirm_task
+fn payout_accounts(fixture: &Fixture, stake: u64) -> Vec<TestAccount> {
+    vec![
+        submitted_task(fixture, NOW),
+        agent_with_locked_collateral(fixture, stake),
+        fixture.stake_vault().with_lamports(SOL + stake),
+        fixture.agent(),
+        funded_escrow(fixture),
+        treasury(),
+        TestAccount::none(), // mint
+        TestAccount::none(), // escrow_vault
+        TestAccount::none(), // agent_token_account
+        TestAccount::none(), // fee_ledger
+        TestAccount::none(), // fee_vault
+        TestAccount::none(), // token_program
+        TestAccount::system_program(),
+    ]
+}
+
+/// Check the payout at `offset` returned the collateral to the agent's stake
+fn assert_collateral_released(accounts: &[AccountInfo], offset: usize, stake: u64) {
+    let agent: Agent = read(&accounts[offset + 1]);
+    let escrow: Escrow = read(&accounts[offset + 4]);
+
+    assert_eq!(agent.total_stake, stake + COLLATERAL);
+    assert_eq!(agent.locked_collateral, 0);
+    assert_eq!(accounts[offset + 2].lamports(), SOL + stake + COLLATERAL);
+    assert_eq!(escrow.collateral, 0);
+    assert_eq!(escrow.balance, 0);
+    assert_eq!(accounts[offset + 3].lamports(), SOL + BOUNTY);
+}
+
+#[test]
+fn claiming_locks_the_collateral_out_of_free_stake() {
+    let fixture = Fixture::new();
+    let accounts = infos(vec![
+        fixture.agent_signer(),
+        fixture.task_with(TaskStatus::Open, |task| {
+            task.agent = None;
+            task.deadline = NOW + 100;
+            task.collateral = COLLATERAL;
+        }),
+        fixture.agent_account_with(|agent| agent.total_stake = 2 * SOL),
+        fixture.stake_vault().with_lamports(SOL + 2 * SOL),
+        fixture.escrow(),
+    ]);
+
+    run::<ClaimTask>(accounts, claim_task::handler).unwrap();
+
+    let agent: Agent = read(&accounts[2]);
+    let escrow: Escrow = read(&accounts[4]);
+    assert_eq!(agent.total_stake, 2 * SOL - COLLATERAL);
+    assert_eq!(agent.locked_collateral, COLLATERAL);
+    assert_eq!(accounts[3].lamports(), SOL + 2 * SOL - COLLATERAL);
+    assert_eq!(escrow.collateral, COLLATERAL);
+    assert_eq!(accounts[4].lamports(), SOL + COLLATERAL);
+}
+
+#[test]
+fn claiming_needs_enough_unbonded_stake_for_the_collateral() {
+    let fixture = Fixture::new();
+    let accounts = infos(vec![
+        fixture.agent_signer(),
+        fixture.task_with(TaskStatus::Open, |task| {
+            task.agent = None;
+            task.deadline = NOW + 100;
+            task.collateral = COLLATERAL;
+        }),
+        fixture.agent_account_with(|agent| {
+            agent.total_stake = SOL;
+            agent.pending_unstake = SOL - COLLATERAL + 1;
+        }),
+        fixture.stake_vault().with_lamports(2 * SOL),
+        fixture.escrow(),
+    ]);
+
+    assert_eq!(
+        run::<ClaimTask>(accounts, claim_task::handler).unwrap_err(),
+        AgentTrustError::InsufficientCollateral.into()
+    );
+}
+
+#[test]
+fn confirming_returns_the_collateral_to_stake() {
+    let fixture = Fixture::new();
+    let mut accounts = vec![fixture.client(true)];
+    accounts.extend(payout_accounts(&fixture, SOL));
+    let accounts = infos(accounts);
+
+    run::<ConfirmTask>(accounts, confirm_task::handler).unwrap();
+
+    assert_collateral_released(accounts, 1, SOL);
+}
+
+#[test]
+fn auto_confirming_returns_the_collateral_to_stake() {
+    let fixture = Fixture::new();
+    let mut accounts = vec![TestAccount::wallet(Pubkey::new_unique(), true), config()];
+    accounts.extend(payout_accounts(&fixture, SOL));
+
+    // Submitted longer ago than the review window
+    let review_window = protocol_config().review_window_seconds;
+    accounts[2] = submitted_task(&fixture, NOW - review_window - 1);
+    let accounts = infos(accounts);
+
+    run::<AutoConfirmTask>(accounts, auto_confirm_task::handler).unwrap();
+
+    assert_collateral_released(accounts, 2, SOL);
+}
+
+#[test]
+fn expiring_slashes_the_collateral_and_a_share_of_stake() {
+    let fixture = Fixture::new();
+    let stake = 10 * SOL;
+    let refund = BOUNTY + FEE;
+    let accounts = infos(vec![
+        TestAccount::wallet(Pubkey::new_unique(), true), // cranker
+        config(),
+        fixture.task_with(TaskStatus::Claimed, |task| {
+            task.bounty = BOUNTY;
+            task.fee = FEE;
+            task.collateral = COLLATERAL;
+            task.deadline = NOW - 1;
+        }),
+        agent_with_locked_collateral(&fixture, stake),
+        fixture.stake_vault().with_lamports(SOL + stake),
+        treasury(),
+        insurance_pool(),
+        fixture.client(false),
+        funded_escrow(&fixture),
+        TestAccount::none(), // mint
+        TestAccount::none(), // escrow_vault
+        TestAccount::none(), // client_token_account
+        TestAccount::none(), // token_program
+    ]);
+
+    run::<ExpireTask>(accounts, expire_task::handler).unwrap();
+
+    // The collateral plus 10% of stake, split 70/20/10 between client, treasury and insurance
+    let slash = COLLATERAL + stake / 10;
+    let agent: Agent = read(&accounts[3]);
+    let treasury: Treasury = read(&accounts[5]);
+    let pool: InsurancePool = read(&accounts[6]);
+    assert_eq!(agent.locked_collateral, 0);
+    assert_eq!(agent.total_stake, stake - stake / 10);
+    assert_eq!(accounts[4].lamports(), SOL + stake - stake / 10);
+    assert_eq!(treasury.total_slashed_lamports, slash / 5);
+    assert_eq!(accounts[5].lamports(), SOL + slash / 5);
+    assert_eq!(pool.total_deposited, slash / 10);
+    assert_eq!(accounts[6].lamports(), SOL + slash / 10);
+
+    // The client also receives the refund and the closed escrow's rent
+    assert_eq!(accounts[7].lamports(), SOL + refund + slash * 7 / 10 + SOL);
+    assert_eq!(accounts[8].lamports(), 0);
+}
+
+#[test]
+fn collateral_can_be_at_most_the_whole_bounty() {
+    install_clock();
+    let config = protocol_config();
+    let create = |bps| {
+        blank::<Task>().create(
+            Pubkey::new_unique(),
+            0,
+            String::new(),
+            [0; 32],
+            BOUNTY,
+            FEE,
+            NOW + 100,
+            Default::default(),
+            Collateral::BountyBps { bps },
+            &config,
+            0,
+        )
+    };
+
+    create(10_000).unwrap();
+    assert_eq!(
+        create(10_001).unwrap_err(),
+        AgentTrustError::CollateralExceedsBounty.into()
+    );
+}
@@ -0,0 +1,319 @@
+//! Fixtures shared by the integration tests: a test clock, zeroed accounts, and
+//! in-memory AccountInfos that instructions can be validated and run against.
+
+#![allow(dead_code)]
+
+use std::collections::BTreeSet;
+use std::sync::Once;
+
+use agenttrust::state::{
+    Agent, ArbitratorRegistry, ClientProfile, Dispute, DisputeQueue, Escrow, InsurancePool,
+    ProtocolConfig, StakeVault, Task, Treasury,
+};
+use agenttrust::{DisputeResolution, DisputeStatus, TaskStatus, ID};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::SUCCESS;
+use anchor_lang::solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+use anchor_lang::{system_program, Bumps, Discriminator};
+
+/// Unix timestamp reported by the test clock
+pub const NOW: i64 = 1_700_000_000;
+
+pub const SOL: u64 = 1_000_000_000;
+
+/// Clock sysvar for deadlines and constraints that compare against the current time
+pub struct TestClock;
+
+impl SyscallStubs for TestClock {
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = Clock {
+            unix_timestamp: NOW,
+            ..Clock::default()
+        };
+        unsafe { *(var_addr as *mut Clock) = clock };
+        SUCCESS
+    }
+}
+
+/// Route `Clock::get()` to the test clock; safe to call from every test
+pub fn install_clock() {
+    static CLOCK: Once = Once::new();
+    CLOCK.call_once(|| {
+        set_syscall_stubs(Box::new(TestClock));
+    });
+}
+
+/// An account with every field zeroed, ready to be filled in by a test
+pub fn blank<T: AccountDeserialize + Discriminator>() -> T {
+    let mut data = T::discriminator().to_vec();
+    data.resize(8 + 2048, 0);
+    T::try_deserialize(&mut data.as_slice()).unwrap()
+}
+
+/// Bytes the runtime keeps in front of account data for its length
+const DATA_HEADER_LEN: usize = 8;
+
+/// Backing storage for one AccountInfo. Laid out like the runtime's input buffer,
+/// which `AccountInfo::realloc` (used when Anchor closes an account) relies on:
+/// the original data length sits right before the key, and the data length right
+/// before the data.
+#[repr(C)]
+pub struct TestAccount {
+    original_data_len: u32,
+    key: Pubkey,
+    owner: Pubkey,
+    lamports: u64,
+    data: Vec<u8>,
+    is_signer: bool,
+    is_writable: bool,
+    executable: bool,
+}
+
+impl TestAccount {
+    fn new(key: Pubkey, owner: Pubkey, lamports: u64, data: &[u8]) -> Self {
+        let mut storage = (data.len() as u64).to_le_bytes().to_vec();
+        storage.extend_from_slice(data);
+        Self {
+            original_data_len: data.len() as u32,
+            key,
+            owner,
+            lamports,
+            data: storage,
+            is_signer: false,
+            is_writable: true,
+            executable: false,
+        }
+    }
+
+    /// A program account holding `account`, padded like an account allocated with
+    /// a fixed size so that its fields can grow
+    pub fn program<T: AccountSerialize>(key: Pubkey, account: &T) -> Self {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        data.resize(data.len().max(8 + 2048), 0);
+        Self::new(key, ID, SOL, &data)
+    }
+
+    pub fn wallet(key: Pubkey, is_signer: bool) -> Self {
+        let mut account = Self::new(key, system_program::ID, SOL, &[]);
+        account.is_signer = is_signer;
+        account
+    }
+
+    /// Placeholder Anchor reads as an omitted optional account
+    pub fn none() -> Self {
+        let mut account = Self::new(ID, Pubkey::default(), 0, &[]);
+        account.is_writable = false;
+        account
+    }
+
+    pub fn system_program() -> Self {
+        let mut account = Self::new(system_program::ID, Pubkey::default(), 1, &[]);
+        account.is_writable = false;
+        account.executable = true;
+        account
+    }
+
+    pub fn with_lamports(mut self, lamports: u64) -> Self {
+        self.lamports = lamports;
+        self
+    }
+
+    fn info(&mut self) -> AccountInfo<'_> {
+        AccountInfo::new(
+            &self.key,
+            self.is_signer,
+            self.is_writable,
+            &mut self.lamports,
+            &mut self.data[DATA_HEADER_LEN..],
+            &self.owner,
+            self.executable,
+            0,
+        )
+    }
+}
+
+pub fn pda(seeds: &[&[u8]]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(seeds, &ID)
+}
+
+/// Leak the accounts so their infos can borrow them for `'static`
+pub fn infos(accounts: Vec<TestAccount>) -> &'static [AccountInfo<'static>] {
+    let accounts: &'static mut [TestAccount] = Box::leak(accounts.into_boxed_slice());
+    let infos: Vec<AccountInfo<'static>> = accounts.iter_mut().map(TestAccount::info).collect();
+    Box::leak(infos.into_boxed_slice())
+}
+
+/// Validate `accounts` for instruction `T` and run its handler, persisting the
+/// accounts afterwards as the runtime would
+pub fn run<T>(
+    accounts: &'static [AccountInfo<'static>],
+    handler: impl FnOnce(Context<'_, '_, 'static, 'static, T>) -> Result<()>,
+) -> Result<()>
+where
+    T: Bumps + Accounts<'static, T::Bumps> + AccountsExit<'static>,
+    T::Bumps: Default,
+{
+    install_clock();
+    let mut remaining = accounts;
+    let mut bumps = T::Bumps::default();
+    let mut validated =
+        T::try_accounts(&ID, &mut remaining, &[], &mut bumps, &mut BTreeSet::new())?;
+    handler(Context::new(&ID, &mut validated, remaining, bumps))?;
+    validated.exit(&ID)
+}
+
+/// Current state of a program account
+pub fn read<T: AccountDeserialize>(info: &AccountInfo) -> T {
+    T::try_deserialize(&mut &info.try_borrow_data().unwrap()[..]).unwrap()
+}
+
+/// Protocol parameters at their defaults
+pub fn protocol_config() -> ProtocolConfig {
+    let mut config = blank::<ProtocolConfig>();
+    config.initialize(Pubkey::new_unique(), Pubkey::new_unique(), 0);
+    config
+}
+
+/// Config PDA with the default parameters
+pub fn config() -> TestAccount {
+    let (key, bump) = pda(&[b"config"]);
+    let mut config = protocol_config();
+    config.bump = bump;
+    TestAccount::program(key, &config)
+}
+
+pub fn treasury() -> TestAccount {
+    let (key, bump) = pda(&[b"treasury"]);
+    let mut treasury = blank::<Treasury>();
+    treasury.bump = bump;
+    TestAccount::program(key, &treasury)
+}
+
+pub fn dispute_queue() -> TestAccount {
+    let (key, bump) = pda(&[b"dispute_queue"]);
+    let mut queue = blank::<DisputeQueue>();
+    queue.bump = bump;
+    TestAccount::program(key, &queue)
+}
+
+pub fn insurance_pool() -> TestAccount {
+    let (key, bump) = pda(&[b"insurance_pool"]);
+    let mut pool = blank::<InsurancePool>();
+    pool.bump = bump;
+    TestAccount::program(key, &pool)
+}
+
+pub fn arbitrator_registry() -> TestAccount {
+    let (key, bump) = pda(&[b"arbitrator_registry"]);
+    let mut registry = blank::<ArbitratorRegistry>();
+    registry.bump = bump;
+    TestAccount::program(key, &registry)
+}
+
+/// A task claimed by its own agent, with the PDAs that belong to it
+pub struct Fixture {
+    pub client: Pubkey,
+    pub agent: Pubkey,
+    pub task: Pubkey,
+}
+
+impl Fixture {
+    pub fn new() -> Self {
+        Self {
+            client: Pubkey::new_unique(),
+            agent: Pubkey::new_unique(),
+            task: Pubkey::new_unique(),
+        }
+    }
+
+    pub fn task(&self, status: TaskStatus) -> TestAccount {
+        self.task_with(status, |_| {})
+    }
+
+    pub fn task_with(&self, status: TaskStatus, edit: impl FnOnce(&mut Task)) -> TestAccount {
+        let mut task = blank::<Task>();
+        task.client = self.client;
+        task.agent = Some(self.agent);
+        task.status = status;
+        edit(&mut task);
+        TestAccount::program(self.task, &task)
+    }
+
+    pub fn agent_address(&self) -> (Pubkey, u8) {
+        pda(&[b"agent", self.agent.as_ref()])
+    }
+
+    pub fn agent_account(&self) -> TestAccount {
+        self.agent_account_with(|_| {})
+    }
+
+    pub fn agent_account_with(&self, edit: impl FnOnce(&mut Agent)) -> TestAccount {
+        let (key, bump) = self.agent_address();
+        let mut agent = blank::<Agent>();
+        agent.owner = self.agent;
+        agent.bump = bump;
+        edit(&mut agent);
+        TestAccount::program(key, &agent)
+    }
+
+    pub fn stake_vault(&self) -> TestAccount {
+        let (agent, _) = self.agent_address();
+        let (key, bump) = pda(&[b"stake_vault", agent.as_ref()]);
+        let mut vault = blank::<StakeVault>();
+        vault.owner = agent;
+        vault.bump = bump;
+        TestAccount::program(key, &vault)
+    }
+
+    pub fn client_profile(&self) -> TestAccount {
+        let (key, bump) = pda(&[b"client", self.client.as_ref()]);
+        let mut profile = blank::<ClientProfile>();
+        profile.client = self.client;
+        profile.bump = bump;
+        TestAccount::program(key, &profile)
+    }
+
+    pub fn escrow(&self) -> TestAccount {
+        self.escrow_with(|_| {})
+    }
+
+    pub fn escrow_with(&self, edit: impl FnOnce(&mut Escrow)) -> TestAccount {
+        let (key, bump) = pda(&[b"escrow", self.task.as_ref()]);
+        let mut escrow = blank::<Escrow>();
+        escrow.task = self.task;
+        escrow.bump = bump;
+        edit(&mut escrow);
+        TestAccount::program(key, &escrow)
+    }
+
+    /// A dispute resolved for the client whose appeal window has closed
+    pub fn dispute(&self) -> TestAccount {
+        self.dispute_with(|dispute| {
+            dispute.resolution = Some(DisputeResolution::ClientWins);
+            dispute.status = DisputeStatus::Resolved;
+            dispute.appeal_deadline = NOW - 1;
+        })
+    }
+
+    pub fn dispute_with(&self, edit: impl FnOnce(&mut Dispute)) -> TestAccount {
+        let (key, bump) = pda(&[b"dispute", self.task.as_ref()]);
+        let mut dispute = blank::<Dispute>();
+        dispute.task = self.task;
+        dispute.bump = bump;
+        edit(&mut dispute);
+        TestAccount::program(key, &dispute)
+    }
+
+    pub fn client(&self, is_signer: bool) -> TestAccount {
+        TestAccount::wallet(self.client, is_signer)
+    }
+
+    pub fn agent(&self) -> TestAccount {
+        TestAccount::wallet(self.agent, false)
+    }
+
+    pub fn agent_signer(&self) -> TestAccount {
+        TestAccount::wallet(self.agent, true)
+    }
+}
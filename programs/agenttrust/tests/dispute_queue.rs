@@ -0,0 +1,91 @@
+//! The judgment work queue: a fixed-size ring of the disputes whose evidence period
+//! closed most recently.
+
+mod common;
+
+use agenttrust::instructions::{close_evidence_period, CloseEvidencePeriod};
+use agenttrust::state::{Dispute, DisputeQueue};
+use agenttrust::{DisputeStatus, MAX_QUEUED_DISPUTES};
+use anchor_lang::prelude::*;
+use common::{blank, infos, pda, read, run, Fixture, TestAccount, NOW};
+
+fn disputes(count: usize) -> Vec<Pubkey> {
+    (0..count).map(|_| Pubkey::new_unique()).collect()
+}
+
+/// A queue holding `disputes`, pushed in order
+fn queue(disputes: &[Pubkey]) -> DisputeQueue {
+    let mut queue = blank::<DisputeQueue>();
+    for dispute in disputes {
+        queue.push(*dispute);
+    }
+    queue
+}
+
+#[test]
+fn disputes_are_queued_in_the_order_they_become_ready() {
+    let disputes = disputes(3);
+
+    assert_eq!(queue(&disputes).disputes, disputes);
+}
+
+#[test]
+fn a_dispute_is_queued_once() {
+    let disputes = disputes(2);
+    let mut queue = queue(&disputes);
+
+    queue.push(disputes[0]);
+
+    assert_eq!(queue.disputes, disputes);
+}
+
+#[test]
+fn a_full_queue_replaces_its_oldest_entries() {
+    let disputes = disputes(MAX_QUEUED_DISPUTES + 2);
+
+    let queue = queue(&disputes);
+
+    assert_eq!(queue.disputes.len(), MAX_QUEUED_DISPUTES);
+    assert_eq!(queue.disputes[..2], disputes[MAX_QUEUED_DISPUTES..]);
+    assert_eq!(queue.disputes[2..], disputes[2..MAX_QUEUED_DISPUTES]);
+    assert_eq!(queue.next, 2);
+}
+
+#[test]
+fn a_full_queue_fits_in_its_account() {
+    let mut queue = queue(&disputes(MAX_QUEUED_DISPUTES));
+    queue.bump = u8::MAX;
+    let mut data = Vec::new();
+
+    queue.try_serialize(&mut data).unwrap();
+
+    assert_eq!(data.len(), DisputeQueue::SPACE);
+}
+
+#[test]
+fn closing_an_evidence_period_queues_the_dispute_even_when_the_queue_is_full() {
+    let fixture = Fixture::new();
+    let (dispute_key, dispute_bump) = pda(&[b"dispute", fixture.task.as_ref()]);
+    let mut dispute = blank::<Dispute>();
+    dispute.task = fixture.task;
+    dispute.status = DisputeStatus::EvidencePeriod;
+    dispute.evidence_deadline = NOW - 1;
+    dispute.bump = dispute_bump;
+    let (queue_key, queue_bump) = pda(&[b"dispute_queue"]);
+    let mut full = queue(&disputes(MAX_QUEUED_DISPUTES));
+    full.bump = queue_bump;
+    let accounts = infos(vec![
+        TestAccount::wallet(Pubkey::new_unique(), true),
+        TestAccount::program(dispute_key, &dispute),
+        TestAccount::program(queue_key, &full),
+    ]);
+
+    run::<CloseEvidencePeriod>(accounts, close_evidence_period::handler).unwrap();
+
+    let dispute: Dispute = read(&accounts[1]);
+    let queue: DisputeQueue = read(&accounts[2]);
+    assert!(dispute.status == DisputeStatus::JudgmentPending);
+    assert_eq!(queue.disputes.len(), MAX_QUEUED_DISPUTES);
+    assert_eq!(queue.disputes[0], dispute_key);
+    assert_eq!(queue.next, 1);
+}
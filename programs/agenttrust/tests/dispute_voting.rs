@@ -0,0 +1,349 @@
+//! Panel majorities, the tally that closes each voting round, and appeals.
+//!
+//! Each test seats a panel on a dispute in a given voting state and checks the
+//! ruling (or the next round) that the dispute's state machine arrives at.
+
+mod common;
+
+use agenttrust::errors::AgentTrustError;
+use agenttrust::state::{Dispute, ProtocolConfig};
+use agenttrust::{DisputeResolution, DisputeStatus, PanelVote, MAX_VOTING_ROUNDS};
+use anchor_lang::prelude::*;
+use common::{blank, install_clock, NOW};
+
+const APPEAL_WINDOW: i64 = 1_000;
+
+fn config() -> ProtocolConfig {
+    let mut config = blank::<ProtocolConfig>();
+    config.commit_window_seconds = 100;
+    config.reveal_window_seconds = 100;
+    config.appeal_window_seconds = APPEAL_WINDOW;
+    config
+}
+
+fn revealed(resolution: DisputeResolution) -> PanelVote {
+    PanelVote::Revealed { resolution }
+}
+
+fn split(agent_bps: u16) -> DisputeResolution {
+    DisputeResolution::Split { agent_bps }
+}
+
+/// A dispute whose panel has cast `votes`, with both voting deadlines still ahead
+fn dispute(votes: Vec<PanelVote>) -> Dispute {
+    install_clock();
+    let mut dispute = blank::<Dispute>();
+    dispute.panel = votes.iter().map(|_| Pubkey::new_unique()).collect();
+    dispute.votes = votes;
+    dispute.status = DisputeStatus::VoteReveal;
+    dispute.commit_deadline = NOW + 10;
+    dispute.reveal_deadline = NOW + 20;
+    dispute
+}
+
+/// Commit a vote for `seat` so that revealing `resolution` with `salt` is accepted
+fn commit(dispute: &mut Dispute, seat: usize, resolution: DisputeResolution, salt: [u8; 32]) {
+    let commitment = Dispute::vote_commitment(&dispute.panel[seat], resolution, &salt).unwrap();
+    dispute.votes[seat] = PanelVote::Committed { commitment };
+}
+
+#[test]
+fn majority_needs_more_than_half_of_the_panel() {
+    let dispute = dispute(vec![
+        revealed(DisputeResolution::ClientWins),
+        revealed(DisputeResolution::ClientWins),
+        revealed(DisputeResolution::AgentWins),
+    ]);
+
+    assert!(dispute.majority() == Some(DisputeResolution::ClientWins));
+}
+
+#[test]
+fn majority_counts_unrevealed_seats_against_every_ruling() {
+    let dispute = dispute(vec![
+        revealed(DisputeResolution::AgentWins),
+        PanelVote::Forfeited,
+        PanelVote::Pending,
+    ]);
+
+    assert!(dispute.majority().is_none());
+}
+
+#[test]
+fn majority_is_none_on_a_tie() {
+    let dispute = dispute(vec![
+        revealed(DisputeResolution::ClientWins),
+        revealed(DisputeResolution::ClientWins),
+        revealed(DisputeResolution::AgentWins),
+        revealed(DisputeResolution::AgentWins),
+    ]);
+
+    assert!(dispute.majority().is_none());
+}
+
+#[test]
+fn majority_groups_splits_with_the_same_share() {
+    let dispute = dispute(vec![
+        revealed(split(3_000)),
+        revealed(split(3_000)),
+        revealed(split(7_000)),
+    ]);
+
+    assert!(dispute.majority() == Some(split(3_000)));
+}
+
+#[test]
+fn majority_does_not_combine_splits_with_different_shares() {
+    let dispute = dispute(vec![
+        revealed(split(3_000)),
+        revealed(split(4_000)),
+        revealed(DisputeResolution::ClientWins),
+    ]);
+
+    assert!(dispute.majority().is_none());
+}
+
+#[test]
+fn last_reveal_resolves_on_a_majority() {
+    let salt = [7; 32];
+    let mut dispute = dispute(vec![
+        revealed(DisputeResolution::AgentWins),
+        revealed(DisputeResolution::ClientWins),
+        PanelVote::Pending,
+    ]);
+    commit(&mut dispute, 2, DisputeResolution::AgentWins, salt);
+    let arbitrator = dispute.panel[2];
+
+    dispute
+        .reveal_vote(arbitrator, DisputeResolution::AgentWins, salt, &config())
+        .unwrap();
+
+    assert!(dispute.status == DisputeStatus::Resolved);
+    assert!(dispute.resolution == Some(DisputeResolution::AgentWins));
+    assert_eq!(dispute.appeal_deadline, NOW + APPEAL_WINDOW);
+}
+
+#[test]
+fn last_reveal_without_a_majority_reruns_the_round() {
+    let salt = [7; 32];
+    let mut dispute = dispute(vec![
+        revealed(DisputeResolution::AgentWins),
+        revealed(DisputeResolution::ClientWins),
+        PanelVote::Pending,
+    ]);
+    commit(&mut dispute, 2, split(5_000), salt);
+    let arbitrator = dispute.panel[2];
+
+    dispute
+        .reveal_vote(arbitrator, split(5_000), salt, &config())
+        .unwrap();
+
+    assert!(dispute.status == DisputeStatus::VoteCommit);
+    assert_eq!(dispute.round, 1);
+    assert!(dispute.votes.iter().all(|vote| *vote == PanelVote::Pending));
+    assert_eq!(dispute.commit_deadline, NOW + 100);
+}
+
+#[test]
+fn closing_the_commit_phase_forfeits_seats_that_never_committed() {
+    let mut dispute = dispute(vec![
+        PanelVote::Committed {
+            commitment: [1; 32],
+        },
+        PanelVote::Committed {
+            commitment: [2; 32],
+        },
+        PanelVote::Pending,
+    ]);
+    dispute.status = DisputeStatus::VoteCommit;
+    dispute.commit_deadline = NOW - 1;
+    let absent = dispute.panel[2];
+
+    let forfeited = dispute.close_phase(&config()).unwrap();
+
+    assert!(forfeited == vec![absent]);
+    assert!(dispute.votes[2] == PanelVote::Forfeited);
+    assert!(dispute.status == DisputeStatus::VoteReveal);
+}
+
+#[test]
+fn closing_a_round_no_seat_can_win_applies_the_fallback_ruling() {
+    let mut dispute = dispute(vec![
+        revealed(DisputeResolution::ClientWins),
+        PanelVote::Committed {
+            commitment: [1; 32],
+        },
+        PanelVote::Pending,
+    ]);
+    dispute.commit_deadline = NOW - 2;
+    dispute.reveal_deadline = NOW - 1;
+
+    let forfeited = dispute.close_phase(&config()).unwrap();
+
+    assert_eq!(forfeited.len(), 2);
+    assert!(dispute.status == DisputeStatus::Resolved);
+    assert!(dispute.resolution == Some(split(5_000)));
+}
+
+#[test]
+fn an_appeal_the_panel_cannot_decide_leaves_the_appealed_ruling() {
+    let mut dispute = dispute(vec![PanelVote::Forfeited; 5]);
+    dispute.appellant = Some(Pubkey::new_unique());
+    dispute.appealed_ruling = Some(DisputeResolution::ClientWins);
+    dispute.votes[0] = PanelVote::Pending;
+    dispute.status = DisputeStatus::VoteCommit;
+    dispute.commit_deadline = NOW - 1;
+
+    dispute.close_phase(&config()).unwrap();
+
+    assert!(dispute.resolution == Some(DisputeResolution::ClientWins));
+    assert_eq!(dispute.appeal_deadline, NOW);
+}
+
+#[test]
+fn the_last_round_without_a_majority_applies_the_fallback_ruling() {
+    let salt = [7; 32];
+    let mut dispute = dispute(vec![
+        revealed(DisputeResolution::AgentWins),
+        revealed(DisputeResolution::ClientWins),
+        PanelVote::Pending,
+    ]);
+    dispute.round = MAX_VOTING_ROUNDS - 1;
+    commit(&mut dispute, 2, split(2_500), salt);
+    let arbitrator = dispute.panel[2];
+
+    dispute
+        .reveal_vote(arbitrator, split(2_500), salt, &config())
+        .unwrap();
+
+    assert!(dispute.status == DisputeStatus::Resolved);
+    assert!(dispute.resolution == Some(split(5_000)));
+}
+
+#[test]
+fn closing_a_phase_before_its_deadline_is_rejected() {
+    let mut dispute = dispute(vec![
+        PanelVote::Committed {
+            commitment: [1; 32]
+        };
+        3
+    ]);
+
+    assert!(dispute.close_phase(&config()).is_err());
+    assert!(dispute.status == DisputeStatus::VoteReveal);
+}
+
+#[test]
+fn reveals_wait_for_seats_that_missed_the_commit_deadline_to_be_forfeited() {
+    let salt = [7; 32];
+    let mut dispute = dispute(vec![PanelVote::Pending; 3]);
+    commit(&mut dispute, 0, DisputeResolution::AgentWins, salt);
+    commit(&mut dispute, 1, DisputeResolution::AgentWins, salt);
+    dispute.status = DisputeStatus::VoteCommit;
+    dispute.commit_deadline = NOW - 1;
+    let arbitrator = dispute.panel[0];
+
+    assert_eq!(
+        dispute
+            .reveal_vote(arbitrator, DisputeResolution::AgentWins, salt, &config())
+            .unwrap_err(),
+        AgentTrustError::CommitPhaseNotClosed.into()
+    );
+    assert!(dispute.votes[2] == PanelVote::Pending);
+
+    let forfeited = dispute.close_phase(&config()).unwrap();
+    dispute
+        .reveal_vote(arbitrator, DisputeResolution::AgentWins, salt, &config())
+        .unwrap();
+
+    assert!(forfeited == vec![dispute.panel[2]]);
+    assert!(dispute.status == DisputeStatus::VoteReveal);
+}
+
+#[test]
+fn reveals_start_early_once_every_seat_has_committed() {
+    let salt = [7; 32];
+    let mut dispute = dispute(vec![PanelVote::Pending; 3]);
+    for seat in 0..3 {
+        commit(&mut dispute, seat, DisputeResolution::ClientWins, salt);
+    }
+    dispute.status = DisputeStatus::VoteCommit;
+    let arbitrator = dispute.panel[0];
+
+    dispute
+        .reveal_vote(arbitrator, DisputeResolution::ClientWins, salt, &config())
+        .unwrap();
+
+    assert!(dispute.status == DisputeStatus::VoteReveal);
+    assert!(dispute.votes[0] == revealed(DisputeResolution::ClientWins));
+}
+
+#[test]
+fn an_appeal_after_a_rerun_ruling_gets_every_voting_round() {
+    let salt = [7; 32];
+    let mut dispute = dispute(vec![revealed(DisputeResolution::AgentWins); 3]);
+    dispute.round = MAX_VOTING_ROUNDS - 1;
+    dispute.status = DisputeStatus::Resolved;
+    dispute.resolution = Some(DisputeResolution::AgentWins);
+    dispute.appeal_deadline = NOW + APPEAL_WINDOW;
+
+    dispute.appeal(Pubkey::new_unique(), true, 0, &config()).unwrap();
+    assert_eq!(dispute.round, 0);
+
+    // An appeal panel without a majority gets a fresh round instead of the fallback
+    dispute
+        .seat((0..5).map(|_| Pubkey::new_unique()).collect())
+        .unwrap();
+    dispute.votes = vec![
+        revealed(DisputeResolution::AgentWins),
+        revealed(DisputeResolution::ClientWins),
+        revealed(split(3_000)),
+        revealed(split(6_000)),
+        PanelVote::Pending,
+    ];
+    commit(&mut dispute, 4, DisputeResolution::ClientWins, salt);
+    dispute.status = DisputeStatus::VoteReveal;
+    let arbitrator = dispute.panel[4];
+
+    dispute
+        .reveal_vote(arbitrator, DisputeResolution::ClientWins, salt, &config())
+        .unwrap();
+
+    assert!(dispute.status == DisputeStatus::VoteCommit);
+    assert_eq!(dispute.round, 1);
+}
+
+#[test]
+fn reveals_wait_while_seats_can_still_commit() {
+    let salt = [7; 32];
+    let mut dispute = dispute(vec![PanelVote::Pending; 3]);
+    commit(&mut dispute, 0, DisputeResolution::AgentWins, salt);
+    dispute.status = DisputeStatus::VoteCommit;
+    let arbitrator = dispute.panel[0];
+
+    assert_eq!(
+        dispute
+            .reveal_vote(arbitrator, DisputeResolution::AgentWins, salt, &config())
+            .unwrap_err(),
+        AgentTrustError::CommitPhaseOpen.into()
+    );
+    assert!(dispute.status == DisputeStatus::VoteCommit);
+}
+
+#[test]
+fn a_default_judgment_cannot_be_appealed() {
+    let mut dispute = dispute(Vec::new());
+    dispute.status = DisputeStatus::JudgmentPending;
+    dispute.evidence_deadline = NOW - 1;
+    dispute.client_evidence_count = 1;
+
+    let ruling = dispute.default_judgment().unwrap();
+
+    assert!(ruling == DisputeResolution::ClientWins);
+    assert!(dispute.is_appeal_window_over());
+    assert_eq!(
+        dispute.appeal(Pubkey::new_unique(), false, 0, &config()).unwrap_err(),
+        AgentTrustError::AppealWindowClosed.into()
+    );
+    assert!(dispute.appellant.is_none());
+}
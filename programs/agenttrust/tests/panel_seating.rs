@@ -0,0 +1,253 @@
+//! Seating dispute panels: a dispute or appeal is only accepted when enough
+//! arbitrators could hear it, and one that still has no panel by its seating
+//! deadline settles without one.
+
+mod common;
+
+use agenttrust::errors::AgentTrustError;
+use agenttrust::instructions::{finalize_unseated_dispute, FinalizeUnseatedDispute};
+use agenttrust::state::{ArbitratorRegistry, Dispute};
+use agenttrust::{DisputeParty, DisputeResolution, DisputeStatus, PanelVote, TaskStatus};
+use anchor_lang::prelude::*;
+use common::{
+    arbitrator_registry, blank, config, infos, install_clock, insurance_pool, protocol_config,
+    read, run, treasury, Fixture, TestAccount, NOW, SOL,
+};
+
+const BOUNTY: u64 = SOL;
+const FEE: u64 = SOL / 100;
+const FILING_BOND: u64 = SOL / 10;
+const APPEAL_BOND: u64 = SOL / 5;
+
+fn registry(arbitrators: &[Pubkey]) -> ArbitratorRegistry {
+    let mut registry = blank::<ArbitratorRegistry>();
+    registry.arbitrators = arbitrators.to_vec();
+    registry
+}
+
+fn arbitrators(count: usize) -> Vec<Pubkey> {
+    (0..count).map(|_| Pubkey::new_unique()).collect()
+}
+
+/// A client's dispute whose panel was due by `seating_deadline` and never seated
+fn unseated(seating_deadline: i64) -> Dispute {
+    install_clock();
+    let mut dispute = blank::<Dispute>();
+    dispute.initiator = Pubkey::new_unique();
+    dispute.initiated_by = DisputeParty::Client;
+    dispute.filing_bond = FILING_BOND;
+    dispute.status = DisputeStatus::JudgmentPending;
+    dispute.seating_deadline = seating_deadline;
+    dispute
+}
+
+/// Accounts for finalize_unseated_dispute on a lamport task with a zero-stake agent
+fn finalize_accounts(fixture: &Fixture, edit: impl FnOnce(&mut Dispute)) -> Vec<TestAccount> {
+    vec![
+        TestAccount::wallet(Pubkey::new_unique(), true), // cranker
+        config(),
+        fixture
+            .dispute_with(|dispute| {
+                dispute.initiator = fixture.client;
+                dispute.initiated_by = DisputeParty::Client;
+                dispute.filing_bond = FILING_BOND;
+                dispute.status = DisputeStatus::JudgmentPending;
+                dispute.seating_deadline = NOW - 1;
+                edit(dispute);
+            })
+            .with_lamports(SOL + FILING_BOND + APPEAL_BOND),
+        fixture.task_with(TaskStatus::Disputed, |task| {
+            task.bounty = BOUNTY;
+            task.fee = FEE;
+        }),
+        fixture.agent_account_with(|agent| agent.active_tasks = 1),
+        fixture.stake_vault(),
+        fixture.client_profile(),
+        fixture.client(false),
+        fixture.agent(),
+        fixture
+            .escrow_with(|escrow| escrow.balance = BOUNTY + FEE)
+            .with_lamports(SOL + BOUNTY + FEE),
+        treasury(),
+        insurance_pool(),
+        arbitrator_registry(),
+        TestAccount::none(), // mint
+        TestAccount::none(), // escrow_vault
+        TestAccount::none(), // client_token_account
+        TestAccount::none(), // agent_token_account
+        TestAccount::none(), // fee_ledger
+        TestAccount::none(), // fee_vault
+        TestAccount::none(), // token_program
+        TestAccount::system_program(),
+    ]
+}
+
+#[test]
+fn a_dispute_needs_enough_arbitrators_besides_its_parties() {
+    let client = Pubkey::new_unique();
+    let agent = Pubkey::new_unique();
+    let mut members = arbitrators(2);
+    members.push(client);
+    let dispute = unseated(NOW);
+    let excluded = dispute.panel_exclusions(client, Some(agent));
+
+    assert_eq!(
+        registry(&members).check_can_seat(3, &excluded).unwrap_err(),
+        AgentTrustError::NotEnoughArbitrators.into()
+    );
+
+    members.push(Pubkey::new_unique());
+    registry(&members).check_can_seat(3, &excluded).unwrap();
+}
+
+#[test]
+fn an_appeal_needs_enough_arbitrators_besides_the_prior_panel() {
+    let config = protocol_config();
+    let prior_panel = arbitrators(3);
+    let mut members = prior_panel.clone();
+    members.extend(arbitrators(4));
+    let mut dispute = unseated(NOW);
+    dispute.panel = prior_panel;
+    dispute.resolution = Some(DisputeResolution::ClientWins);
+    dispute.status = DisputeStatus::Resolved;
+    dispute.appeal_deadline = NOW + 1;
+    dispute
+        .appeal(Pubkey::new_unique(), false, APPEAL_BOND, &config)
+        .unwrap();
+    let excluded = dispute.panel_exclusions(Pubkey::new_unique(), None);
+
+    assert_eq!(
+        registry(&members)
+            .check_can_seat(dispute.panel_size(&config), &excluded)
+            .unwrap_err(),
+        AgentTrustError::NotEnoughArbitrators.into()
+    );
+
+    members.push(Pubkey::new_unique());
+    registry(&members)
+        .check_can_seat(dispute.panel_size(&config), &excluded)
+        .unwrap();
+}
+
+#[test]
+fn an_appeal_must_be_seated_within_the_seating_window() {
+    let config = protocol_config();
+    let mut dispute = unseated(NOW);
+    dispute.resolution = Some(DisputeResolution::ClientWins);
+    dispute.status = DisputeStatus::Resolved;
+    dispute.appeal_deadline = NOW + 1;
+
+    dispute
+        .appeal(Pubkey::new_unique(), false, APPEAL_BOND, &config)
+        .unwrap();
+
+    assert_eq!(
+        dispute.seating_deadline,
+        NOW + config.seating_window_seconds
+    );
+}
+
+#[test]
+fn no_panel_can_be_seated_after_the_seating_deadline() {
+    let mut dispute = unseated(NOW - 1);
+
+    assert_eq!(
+        dispute.seat(arbitrators(3)).unwrap_err(),
+        AgentTrustError::SeatingWindowClosed.into()
+    );
+
+    let mut dispute = unseated(NOW);
+    dispute.seat(arbitrators(3)).unwrap();
+}
+
+#[test]
+fn a_dispute_settles_without_a_panel_only_after_the_seating_deadline() {
+    assert_eq!(
+        unseated(NOW).lapse_seating().unwrap_err(),
+        AgentTrustError::SeatingWindowOpen.into()
+    );
+
+    let mut dispute = unseated(NOW - 1);
+    dispute.panel = arbitrators(3);
+    assert_eq!(
+        dispute.lapse_seating().unwrap_err(),
+        AgentTrustError::PanelAlreadySeated.into()
+    );
+}
+
+#[test]
+fn an_unseated_appeal_leaves_the_appealed_ruling_with_its_panel() {
+    let mut dispute = unseated(NOW - 1);
+    let appellant = Pubkey::new_unique();
+    let prior_panel = arbitrators(3);
+    let prior_votes = vec![
+        PanelVote::Revealed {
+            resolution: DisputeResolution::ClientWins,
+        };
+        3
+    ];
+    dispute.appellant = Some(appellant);
+    dispute.appealed_ruling = Some(DisputeResolution::ClientWins);
+    dispute.appeal_bond = APPEAL_BOND;
+    dispute.prior_panel = prior_panel.clone();
+    dispute.prior_votes = prior_votes.clone();
+
+    assert_eq!(dispute.lapse_seating().unwrap(), (appellant, APPEAL_BOND));
+    assert!(dispute.resolution == Some(DisputeResolution::ClientWins));
+    assert!(dispute.appeal_upheld().is_none());
+    assert_eq!(dispute.panel, prior_panel);
+    assert!(dispute.votes == prior_votes);
+    assert_eq!(dispute.filing_bond, FILING_BOND);
+    assert!(dispute.is_appeal_window_over());
+}
+
+#[test]
+fn an_unseated_appeal_settles_on_the_appealed_ruling_and_returns_the_appeal_bond() {
+    let fixture = Fixture::new();
+    let accounts = infos(finalize_accounts(&fixture, |dispute| {
+        dispute.appellant = Some(fixture.agent);
+        dispute.appealed_ruling = Some(DisputeResolution::ClientWins);
+        dispute.appeal_bond = APPEAL_BOND;
+    }));
+
+    run::<FinalizeUnseatedDispute>(accounts, finalize_unseated_dispute::handler).unwrap();
+
+    let dispute: Dispute = read(&accounts[2]);
+    assert!(dispute.resolution == Some(DisputeResolution::ClientWins));
+    assert!(dispute.status == DisputeStatus::Resolved);
+    assert_eq!(accounts[2].lamports(), SOL);
+    // The client wins back the escrow and, as the winning filer, its filing bond
+    assert_eq!(accounts[7].lamports(), SOL + BOUNTY + FEE + FILING_BOND);
+    assert_eq!(accounts[8].lamports(), SOL + APPEAL_BOND);
+}
+
+#[test]
+fn an_unseated_dispute_takes_the_fallback_ruling_and_returns_the_filing_bond() {
+    let fixture = Fixture::new();
+    let accounts = infos(finalize_accounts(&fixture, |_| {}));
+
+    run::<FinalizeUnseatedDispute>(accounts, finalize_unseated_dispute::handler).unwrap();
+
+    let dispute: Dispute = read(&accounts[2]);
+    assert!(dispute.resolution == Some(DisputeResolution::Split { agent_bps: 5_000 }));
+    assert_eq!(dispute.filing_bond, 0);
+    assert_eq!(accounts[2].lamports(), SOL + APPEAL_BOND);
+    assert_eq!(
+        accounts[7].lamports(),
+        SOL + FILING_BOND + BOUNTY / 2 + FEE / 2
+    );
+    assert_eq!(accounts[8].lamports(), SOL + BOUNTY / 2);
+}
+
+#[test]
+fn a_dispute_with_time_left_to_seat_a_panel_cannot_be_finalized() {
+    let fixture = Fixture::new();
+    let accounts = infos(finalize_accounts(&fixture, |dispute| {
+        dispute.seating_deadline = NOW;
+    }));
+
+    assert_eq!(
+        run::<FinalizeUnseatedDispute>(accounts, finalize_unseated_dispute::handler).unwrap_err(),
+        AgentTrustError::SeatingWindowOpen.into()
+    );
+}
@@ -0,0 +1,151 @@
+//! The agent slashing policy: how much a slash takes, where it is taken from and
+//! how it is divided.
+//!
+//! Every test runs against the default protocol parameters: 10% of the bounty plus
+//! 5% of free stake per dispute, +50% per prior loss up to 3x, capped at 10 SOL,
+//! and routed 70% to the client, 20% to the treasury and 10% to insurance.
+
+mod common;
+
+use agenttrust::state::{Agent, AgentSlash, InsurancePool, ProtocolConfig, Task, BPS_DENOMINATOR};
+use anchor_lang::prelude::*;
+use common::{blank, protocol_config, SOL};
+
+fn config() -> ProtocolConfig {
+    protocol_config()
+}
+
+/// A lamport task with a 1 SOL bounty
+fn task() -> Task {
+    let mut task = blank::<Task>();
+    task.bounty = SOL;
+    task
+}
+
+/// An agent with 10 SOL of free stake and `dispute_losses` prior losses
+fn agent(dispute_losses: u64) -> Agent {
+    let mut agent = blank::<Agent>();
+    agent.total_stake = 10 * SOL;
+    agent.dispute_losses = dispute_losses;
+    agent
+}
+
+fn slash(collateral: u64, stake: u64) -> AgentSlash {
+    AgentSlash { collateral, stake }
+}
+
+#[test]
+fn a_lost_dispute_takes_the_collateral_before_free_stake() {
+    // 0.5 SOL collateral + 0.1 SOL of bounty + 0.5 SOL of stake
+    let slash_taken =
+        config().calculate_dispute_slash(&task(), &agent(0), SOL / 2, BPS_DENOMINATOR);
+
+    assert_eq!(slash_taken, slash(SOL / 2, 6 * SOL / 10));
+}
+
+#[test]
+fn each_prior_loss_escalates_the_slash() {
+    // Two prior losses double the 1.1 SOL base
+    let slash_taken =
+        config().calculate_dispute_slash(&task(), &agent(2), SOL / 2, BPS_DENOMINATOR);
+
+    assert_eq!(slash_taken.total(), 22 * SOL / 10);
+    assert_eq!(slash_taken.collateral, SOL / 2);
+}
+
+#[test]
+fn escalation_stops_at_the_maximum_multiplier() {
+    let slash_taken =
+        config().calculate_dispute_slash(&task(), &agent(10), SOL / 2, BPS_DENOMINATOR);
+
+    assert_eq!(slash_taken.total(), 33 * SOL / 10);
+}
+
+#[test]
+fn a_slash_never_exceeds_the_cap_even_with_collateral() {
+    let mut agent = agent(0);
+    agent.total_stake = 1_000 * SOL;
+
+    let slash_taken = config().calculate_dispute_slash(&task(), &agent, 20 * SOL, BPS_DENOMINATOR);
+
+    // Collateral above the cap stays with the agent
+    assert_eq!(slash_taken, slash(10 * SOL, 0));
+}
+
+#[test]
+fn a_split_ruling_scales_the_slash_by_the_clients_share() {
+    let slash_taken = config().calculate_dispute_slash(&task(), &agent(0), SOL / 2, 5_000);
+
+    assert_eq!(slash_taken, slash(SOL / 2, SOL / 20));
+}
+
+#[test]
+fn an_agent_win_slashes_nothing() {
+    let slash_taken = config().calculate_dispute_slash(&task(), &agent(3), SOL / 2, 0);
+
+    assert_eq!(slash_taken, slash(0, 0));
+}
+
+#[test]
+fn token_bounties_leave_out_the_bounty_share() {
+    let mut task = task();
+    task.mint = Some(Pubkey::new_unique());
+
+    let slash_taken = config().calculate_dispute_slash(&task, &agent(0), 0, BPS_DENOMINATOR);
+
+    assert_eq!(slash_taken, slash(0, SOL / 2));
+}
+
+#[test]
+fn an_agent_holding_less_than_the_slash_loses_what_it_has() {
+    let mut agent = agent(2);
+    agent.total_stake = SOL / 10;
+
+    let slash_taken = config().calculate_dispute_slash(&task(), &agent, SOL / 2, BPS_DENOMINATOR);
+
+    assert_eq!(slash_taken, slash(SOL / 2, SOL / 10));
+}
+
+#[test]
+fn an_expired_task_takes_the_collateral_and_a_share_of_stake() {
+    // 10% of the 10 SOL stake, without escalation
+    let slash_taken = config().calculate_expiry_slash(&agent(5), SOL / 2);
+
+    assert_eq!(slash_taken, slash(SOL / 2, SOL));
+}
+
+#[test]
+fn expiry_slashes_are_capped_too() {
+    let slash_taken = config().calculate_expiry_slash(&agent(0), 12 * SOL);
+
+    assert_eq!(slash_taken, slash(10 * SOL, 0));
+}
+
+#[test]
+fn a_slash_is_split_between_client_treasury_and_insurance() {
+    let split = config().split_slash(10 * SOL);
+
+    assert_eq!(split.client, 7 * SOL);
+    assert_eq!(split.treasury, 2 * SOL);
+    assert_eq!(split.insurance, SOL);
+}
+
+#[test]
+fn rounding_leftovers_of_a_split_go_to_insurance() {
+    let split = config().split_slash(999);
+
+    assert_eq!(split.client, 699);
+    assert_eq!(split.treasury, 199);
+    assert_eq!(split.insurance, 101);
+}
+
+#[test]
+fn insurance_withdrawals_are_limited_to_deposits() {
+    let mut pool = blank::<InsurancePool>();
+    pool.record_deposit(SOL).unwrap();
+    pool.record_withdrawal(SOL / 4).unwrap();
+
+    assert_eq!(pool.available(), 3 * SOL / 4);
+    assert!(pool.record_withdrawal(SOL).is_err());
+    assert_eq!(pool.total_withdrawn, SOL / 4);
+}
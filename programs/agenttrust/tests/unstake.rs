@@ -0,0 +1,101 @@
+//! Unbonding agent stake: a request starts the unbonding period and the stake can
+//! only be withdrawn once it has passed with no tasks in flight.
+
+mod common;
+
+use agenttrust::errors::AgentTrustError;
+use agenttrust::state::Agent;
+use common::{blank, install_clock, NOW, SOL};
+
+/// An agent with 10 SOL staked and nothing unbonding
+fn agent() -> Agent {
+    install_clock();
+    let mut agent = blank::<Agent>();
+    agent.total_stake = 10 * SOL;
+    agent
+}
+
+#[test]
+fn stake_is_withdrawn_once_the_unbonding_period_has_passed() {
+    let mut agent = agent();
+    agent.request_unstake(4 * SOL, NOW).unwrap();
+
+    assert_eq!(agent.complete_unstake().unwrap(), 4 * SOL);
+    assert_eq!(agent.total_stake, 6 * SOL);
+    assert_eq!(agent.pending_unstake, 0);
+    assert_eq!(agent.unstake_available_at, 0);
+}
+
+#[test]
+fn stake_cannot_be_withdrawn_before_the_unbonding_period_ends() {
+    let mut agent = agent();
+    agent.request_unstake(4 * SOL, NOW + 1).unwrap();
+
+    assert_eq!(
+        agent.complete_unstake().unwrap_err(),
+        AgentTrustError::UnbondingNotComplete.into()
+    );
+    assert_eq!(agent.total_stake, 10 * SOL);
+    assert_eq!(agent.pending_unstake, 4 * SOL);
+}
+
+#[test]
+fn stake_cannot_be_withdrawn_without_a_request() {
+    assert_eq!(
+        agent().complete_unstake().unwrap_err(),
+        AgentTrustError::NoPendingUnstake.into()
+    );
+}
+
+#[test]
+fn unstaking_is_refused_while_tasks_are_active() {
+    let mut agent = agent();
+    agent.begin_task();
+
+    assert_eq!(
+        agent.request_unstake(SOL, NOW).unwrap_err(),
+        AgentTrustError::ActiveTasksOutstanding.into()
+    );
+    assert_eq!(agent.pending_unstake, 0);
+}
+
+#[test]
+fn withdrawing_is_refused_while_tasks_are_active() {
+    let mut agent = agent();
+    agent.request_unstake(SOL, NOW).unwrap();
+    agent.begin_task();
+
+    assert_eq!(
+        agent.complete_unstake().unwrap_err(),
+        AgentTrustError::ActiveTasksOutstanding.into()
+    );
+
+    agent.finish_task();
+    assert_eq!(agent.complete_unstake().unwrap(), SOL);
+}
+
+#[test]
+fn a_second_request_adds_to_the_first_and_restarts_the_timer() {
+    let mut agent = agent();
+    agent.request_unstake(4 * SOL, NOW).unwrap();
+    agent.request_unstake(2 * SOL, NOW + 100).unwrap();
+
+    assert_eq!(agent.pending_unstake, 6 * SOL);
+    assert_eq!(agent.unstake_available_at, NOW + 100);
+    assert_eq!(
+        agent.complete_unstake().unwrap_err(),
+        AgentTrustError::UnbondingNotComplete.into()
+    );
+}
+
+#[test]
+fn requests_cannot_add_up_to_more_than_the_stake() {
+    let mut agent = agent();
+    agent.request_unstake(6 * SOL, NOW).unwrap();
+
+    assert_eq!(
+        agent.request_unstake(4 * SOL + 1, NOW).unwrap_err(),
+        AgentTrustError::InsufficientStake.into()
+    );
+    assert_eq!(agent.pending_unstake, 6 * SOL);
+}